@@ -85,7 +85,10 @@ async fn main(_s: Spawner) {
     rng.blocking_fill_bytes(&mut seed);
 
     Timer::after(Duration::from_millis(10)).await;
-    let config = SdcConfig { seed };
+    let config = SdcConfig {
+        seed,
+        ..Default::default()
+    };
     sdc_init(config).unwrap();
 
     let mut hci_buf = [0; raw::HCI_MSG_BUFFER_MAX_SIZE as usize];
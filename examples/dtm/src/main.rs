@@ -0,0 +1,96 @@
+//! Direct Test Mode over the 2-wire UART interface, for RF testers.
+//!
+//! The UART runs at 19200 baud, 8N1, on the pins of the nRF52833 DK's
+//! interface MCU: RXD on P0.08 and TXD on P0.06.
+#![no_std]
+#![no_main]
+
+use defmt::{info, unwrap, warn};
+use embassy_executor::Spawner;
+use embassy_nrf::{bind_interrupts, interrupt, peripherals, rng, uarte};
+use embassy_time::{with_timeout, Duration, Timer};
+use interrupt::InterruptExt as _;
+use nrf_sdc::{
+    dtm::Dtm,
+    mpsl::{mpsl_init, mpsl_run, Config as MpslConfig, LfClock},
+    sdc::{sdc_init, Config as SdcConfig},
+};
+use {defmt_rtt as _, panic_probe as _};
+
+bind_interrupts!(struct Irqs {
+    RNG => embassy_nrf::rng::InterruptHandler<peripherals::RNG>;
+    UARTE0_UART0 => uarte::InterruptHandler<peripherals::UARTE0>;
+    SWI0_EGU0 => nrf_sdc::mpsl::LowPrioInterruptHandler;
+    POWER_CLOCK => nrf_sdc::mpsl::ClockInterruptHandler;
+    RADIO => nrf_sdc::mpsl::HighPrioInterruptHandler;
+    TIMER0 => nrf_sdc::mpsl::HighPrioInterruptHandler;
+    RTC0 => nrf_sdc::mpsl::HighPrioInterruptHandler;
+});
+
+/// A command byte without its pair is dropped after this long.
+const BYTE_TIMEOUT: Duration = Duration::from_millis(5);
+
+#[embassy_executor::task]
+async fn mpsl_task() {
+    loop {
+        mpsl_run().await
+    }
+}
+
+#[embassy_executor::main]
+async fn main(s: Spawner) {
+    let p = embassy_nrf::init(Default::default());
+
+    interrupt::RTC0.set_priority(interrupt::Priority::P0);
+    interrupt::RADIO.set_priority(interrupt::Priority::P0);
+    interrupt::TIMER0.set_priority(interrupt::Priority::P0);
+    interrupt::POWER_CLOCK.set_priority(interrupt::Priority::P4);
+    interrupt::SWI0_EGU0.set_priority(interrupt::Priority::P4);
+
+    let config = MpslConfig {
+        source: LfClock::Rc,
+        rc_ctiv: 16,
+        rc_temp_ctiv: 2,
+        accuracy_ppm: 250,
+    };
+    unwrap!(mpsl_init(config, Irqs));
+    Timer::after(Duration::from_millis(10)).await;
+    unwrap!(s.spawn(mpsl_task()));
+
+    let mut rng = rng::Rng::new(p.RNG, Irqs);
+    rng.set_bias_correction(true);
+    let mut seed = [0u8; 32];
+    rng.blocking_fill_bytes(&mut seed);
+
+    let config = SdcConfig {
+        seed,
+        ..Default::default()
+    };
+    unwrap!(sdc_init(config));
+
+    let mut config = uarte::Config::default();
+    config.parity = uarte::Parity::EXCLUDED;
+    config.baudrate = uarte::Baudrate::BAUD19200;
+    let uart = uarte::Uarte::new(p.UARTE0, Irqs, p.P0_08, p.P0_06, config);
+    let (mut tx, mut rx) = uart.split();
+
+    let mut dtm = Dtm::new();
+    info!("DTM ready");
+    loop {
+        let mut msb = [0u8; 1];
+        unwrap!(rx.read(&mut msb).await);
+        let mut lsb = [0u8; 1];
+        match with_timeout(BYTE_TIMEOUT, rx.read(&mut lsb)).await {
+            Ok(res) => unwrap!(res),
+            Err(_) => {
+                warn!("dropping lone byte {:02x}", msb[0]);
+                continue;
+            }
+        }
+
+        let cmd = u16::from_be_bytes([msb[0], lsb[0]]);
+        let response = dtm.process(cmd).await;
+        info!("{:04x} -> {:?}", cmd, response);
+        unwrap!(tx.write(&response.to_bytes()).await);
+    }
+}
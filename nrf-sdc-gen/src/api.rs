@@ -0,0 +1,304 @@
+//! The API described by a bindings file, for comparing the committed bindings
+//! with the ones generated from the headers item by item.
+
+use quote::ToTokens;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+/// Layout of a struct or union, with the size, alignment and offsets checked
+/// by the bindgen layout tests.
+#[derive(Default, PartialEq)]
+struct Layout {
+    repr: String,
+    fields: Vec<(String, String)>,
+    size: Option<u64>,
+    align: Option<u64>,
+    offsets: BTreeMap<String, u64>,
+}
+
+#[derive(Default)]
+pub struct Api {
+    functions: BTreeMap<String, String>,
+    layouts: BTreeMap<String, Layout>,
+    constants: BTreeMap<String, String>,
+    types: BTreeMap<String, String>,
+}
+
+impl Api {
+    pub fn parse(source: &str) -> syn::Result<Self> {
+        let file = syn::parse_file(source)?;
+        let mut api = Self::default();
+        for item in file.items {
+            match item {
+                syn::Item::ForeignMod(block) => {
+                    for item in block.items {
+                        match item {
+                            syn::ForeignItem::Fn(f) => {
+                                api.functions.insert(f.sig.ident.to_string(), tokens(&f.sig));
+                            }
+                            syn::ForeignItem::Static(s) => {
+                                let mutability = if matches!(s.mutability, syn::StaticMutability::Mut(_)) {
+                                    "mut "
+                                } else {
+                                    ""
+                                };
+                                let value = format!("static {}{}: {}", mutability, s.ident, tokens(&s.ty));
+                                api.constants.insert(s.ident.to_string(), value);
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+                syn::Item::Struct(s) => {
+                    let layout = api.layouts.entry(s.ident.to_string()).or_default();
+                    layout.repr = repr(&s.attrs);
+                    layout.fields = fields(s.fields.iter());
+                }
+                syn::Item::Union(u) => {
+                    let layout = api.layouts.entry(u.ident.to_string()).or_default();
+                    layout.repr = repr(&u.attrs);
+                    layout.fields = fields(u.fields.named.iter());
+                }
+                syn::Item::Const(c) => {
+                    let value = format!("const {}: {} = {}", c.ident, tokens(&c.ty), tokens(&c.expr));
+                    api.constants.insert(c.ident.to_string(), value);
+                }
+                syn::Item::Type(t) => {
+                    let value = format!("type {} = {}", t.ident, tokens(&t.ty));
+                    api.types.insert(t.ident.to_string(), value);
+                }
+                syn::Item::Fn(f) => {
+                    if let Some(name) = f.sig.ident.to_string().strip_prefix("bindgen_test_layout_") {
+                        layout_test(&f, api.layouts.entry(name.to_string()).or_default());
+                    }
+                }
+                _ => {}
+            }
+        }
+        Ok(api)
+    }
+
+    /// The changes from `self` to `new`, empty if the APIs are the same.
+    pub fn diff(&self, new: &Self) -> String {
+        let mut out = String::new();
+        let signature = |_: &str, item: &String| item.clone();
+        diff_section(
+            &mut out,
+            "Functions",
+            &self.functions,
+            &new.functions,
+            signature,
+            diff_item,
+        );
+        diff_section(
+            &mut out,
+            "Structs and unions",
+            &self.layouts,
+            &new.layouts,
+            Layout::describe,
+            diff_layout,
+        );
+        diff_section(
+            &mut out,
+            "Constants",
+            &self.constants,
+            &new.constants,
+            signature,
+            diff_item,
+        );
+        diff_section(&mut out, "Types", &self.types, &new.types, signature, diff_item);
+        out
+    }
+}
+
+impl Layout {
+    fn describe(name: &str, layout: &Self) -> String {
+        let mut out = format!(
+            "{}, size {}, align {}",
+            name,
+            or_unknown(layout.size),
+            or_unknown(layout.align)
+        );
+        for (name, ty) in &layout.fields {
+            write!(out, "\n      {}: {}", name, ty).unwrap();
+            if let Some(offset) = layout.offsets.get(name) {
+                write!(out, " @ {}", offset).unwrap();
+            }
+        }
+        out
+    }
+}
+
+fn diff_section<T: PartialEq>(
+    out: &mut String,
+    title: &str,
+    old: &BTreeMap<String, T>,
+    new: &BTreeMap<String, T>,
+    describe: impl Fn(&str, &T) -> String,
+    changed: impl Fn(&mut String, &str, &T, &T),
+) {
+    let mut section = String::new();
+    for (name, item) in old {
+        match new.get(name) {
+            None => writeln!(section, "  - {}", describe(name, item)).unwrap(),
+            Some(new_item) if new_item != item => changed(&mut section, name, item, new_item),
+            Some(_) => {}
+        }
+    }
+    for (name, item) in new {
+        if !old.contains_key(name) {
+            writeln!(section, "  + {}", describe(name, item)).unwrap();
+        }
+    }
+    if !section.is_empty() {
+        writeln!(out, "{}:\n{}", title, section).unwrap();
+    }
+}
+
+fn diff_item(out: &mut String, name: &str, old: &String, new: &String) {
+    writeln!(out, "  ~ {}\n      - {}\n      + {}", name, old, new).unwrap();
+}
+
+fn diff_layout(out: &mut String, name: &str, old: &Layout, new: &Layout) {
+    writeln!(out, "  ~ {}", name).unwrap();
+    if old.repr != new.repr {
+        writeln!(out, "      repr {} -> {}", old.repr, new.repr).unwrap();
+    }
+    if old.size != new.size {
+        writeln!(out, "      size {} -> {}", or_unknown(old.size), or_unknown(new.size)).unwrap();
+    }
+    if old.align != new.align {
+        writeln!(
+            out,
+            "      align {} -> {}",
+            or_unknown(old.align),
+            or_unknown(new.align)
+        )
+        .unwrap();
+    }
+    let old_fields: BTreeMap<_, _> = old.fields.iter().cloned().collect();
+    let new_fields: BTreeMap<_, _> = new.fields.iter().cloned().collect();
+    for (field, ty) in &old.fields {
+        match new_fields.get(field) {
+            None => writeln!(out, "      - {}: {}", field, ty).unwrap(),
+            Some(new_ty) if new_ty != ty => writeln!(out, "      ~ {}: {} -> {}", field, ty, new_ty).unwrap(),
+            Some(_) => {}
+        }
+    }
+    for (field, ty) in &new.fields {
+        if !old_fields.contains_key(field) {
+            writeln!(out, "      + {}: {}", field, ty).unwrap();
+        }
+    }
+    for (field, offset) in &old.offsets {
+        if let Some(new_offset) = new.offsets.get(field).filter(|o| *o != offset) {
+            writeln!(out, "      offset of {} {} -> {}", field, offset, new_offset).unwrap();
+        }
+    }
+    let old_order: Vec<_> = old
+        .fields
+        .iter()
+        .map(|(f, _)| f)
+        .filter(|f| new_fields.contains_key(*f))
+        .collect();
+    let new_order: Vec<_> = new
+        .fields
+        .iter()
+        .map(|(f, _)| f)
+        .filter(|f| old_fields.contains_key(*f))
+        .collect();
+    if old_order != new_order {
+        writeln!(out, "      fields reordered").unwrap();
+    }
+}
+
+fn or_unknown(value: Option<u64>) -> String {
+    value.map_or_else(|| "?".to_string(), |v| v.to_string())
+}
+
+fn repr(attrs: &[syn::Attribute]) -> String {
+    let reprs: Vec<_> = attrs
+        .iter()
+        .filter(|a| a.path().is_ident("repr"))
+        .map(|a| tokens(&a.meta))
+        .collect();
+    reprs.join(" ")
+}
+
+fn fields<'a>(fields: impl Iterator<Item = &'a syn::Field>) -> Vec<(String, String)> {
+    fields
+        .enumerate()
+        .map(|(index, field)| {
+            let name = field
+                .ident
+                .as_ref()
+                .map_or_else(|| index.to_string(), |i| i.to_string());
+            (name, tokens(&field.ty))
+        })
+        .collect()
+}
+
+/// Read the size, alignment and field offsets asserted by a layout test.
+fn layout_test(test: &syn::ItemFn, layout: &mut Layout) {
+    let field = regex::Regex::new(r"\(\*ptr\)\.(?P<field>\w+)").unwrap();
+    for stmt in &test.block.stmts {
+        let syn::Stmt::Macro(stmt) = stmt else {
+            continue;
+        };
+        if !stmt.mac.path.is_ident("assert_eq") {
+            continue;
+        }
+        let Ok(args) = stmt
+            .mac
+            .parse_body_with(syn::punctuated::Punctuated::<syn::Expr, syn::Token![,]>::parse_terminated)
+        else {
+            continue;
+        };
+        let (Some(actual), Some(syn::Expr::Lit(expected))) = (args.iter().next(), args.iter().nth(1)) else {
+            continue;
+        };
+        let syn::Lit::Int(expected) = &expected.lit else {
+            continue;
+        };
+        let Ok(expected) = expected.base10_parse::<u64>() else {
+            continue;
+        };
+        let actual = tokens(actual);
+        if actual.contains("size_of::<") {
+            layout.size = Some(expected);
+        } else if actual.contains("align_of::<") {
+            layout.align = Some(expected);
+        } else if let Some(captures) = field.captures(&actual) {
+            layout.offsets.insert(captures["field"].to_string(), expected);
+        }
+    }
+}
+
+/// The tokens of `item` as a string, spaced the way rustfmt would.
+fn tokens(item: &impl ToTokens) -> String {
+    let mut s = item.to_token_stream().to_string();
+    for (from, to) in [
+        (" :: ", "::"),
+        (":: ", "::"),
+        (" ::", "::"),
+        ("< ", "<"),
+        (" <", "<"),
+        (" >", ">"),
+        ("( ", "("),
+        (" (", "("),
+        (" )", ")"),
+        ("[ ", "["),
+        (" ]", "]"),
+        (" ,", ","),
+        (" ;", ";"),
+        (" . ", "."),
+        (" : ", ": "),
+        ("* ", "*"),
+        ("& ", "&"),
+        ("! ", "!"),
+        (" !", "!"),
+    ] {
+        s = s.replace(from, to);
+    }
+    s
+}
@@ -0,0 +1,510 @@
+//! Conversion of the Doxygen comments of the nrfxlib headers to rustdoc.
+//!
+//! [`to_rustdoc`] converts each comment as bindgen reads it, and
+//! [`resolve_links`] then checks the intra-doc links it made against the
+//! generated items.
+
+use std::collections::HashSet;
+
+/// Converts the comments of the parsed headers.
+#[derive(Debug)]
+pub struct Callbacks;
+
+impl bindgen::callbacks::ParseCallbacks for Callbacks {
+    fn process_comment(&self, comment: &str) -> Option<String> {
+        Some(to_rustdoc(comment))
+    }
+}
+
+/// Block commands, which start a new part of the comment.
+const BLOCK_COMMANDS: &[&str] = &[
+    "addtogroup",
+    "attention",
+    "brief",
+    "code",
+    "defgroup",
+    "deprecated",
+    "details",
+    "file",
+    "ingroup",
+    "name",
+    "note",
+    "param",
+    "remark",
+    "remarks",
+    "result",
+    "retval",
+    "return",
+    "returns",
+    "sa",
+    "see",
+    "short",
+    "tparam",
+    "warning",
+    "{",
+    "}",
+];
+
+struct Param {
+    name: String,
+    direction: Option<String>,
+    text: Vec<String>,
+}
+
+/// A part of the comment, in the order of the comment.
+enum Block {
+    Paragraph(Vec<String>),
+    Heading(String),
+    Code(Vec<String>),
+    Note(&'static str, Vec<String>),
+    Params(Vec<Param>),
+    Returns(Vec<String>),
+    Retvals(Vec<(String, Vec<String>)>),
+    SeeAlso(Vec<String>),
+}
+
+impl Block {
+    /// The text that continuation lines are appended to.
+    fn text(&mut self) -> Option<&mut Vec<String>> {
+        match self {
+            Block::Paragraph(text) | Block::Note(_, text) | Block::Returns(text) | Block::SeeAlso(text) => Some(text),
+            Block::Params(params) => params.last_mut().map(|p| &mut p.text),
+            Block::Retvals(retvals) => retvals.last_mut().map(|(_, text)| text),
+            Block::Heading(_) | Block::Code(_) => None,
+        }
+    }
+}
+
+/// Convert a Doxygen comment, with the comment markers already removed, to
+/// rustdoc Markdown.
+pub fn to_rustdoc(comment: &str) -> String {
+    let blocks = parse(comment);
+
+    let mut parts = Vec::new();
+    let mut returns_heading = false;
+    // Doxygen links the names listed by `@sa` without `@ref`. They become
+    // shortcut links, which `resolve_links` keeps only for generated items.
+    let name = regex::Regex::new(r"\[`[^`]*`\](?:\([^)]*\))?|`[^`]*`|\\.|\b(?P<name>[A-Za-z_]\w*(?:\(\))?)").unwrap();
+    for block in &blocks {
+        let part = match block {
+            Block::Paragraph(text) => inline_lines(text).join("\n"),
+            Block::Heading(title) => format!("# {}", inline(title)),
+            Block::Code(lines) => format!("```c\n{}\n```", dedent(lines).join("\n")),
+            Block::Note(kind, text) => {
+                let mut lines = inline_lines(text);
+                lines[0] = format!("**{}:** {}", kind, lines[0]);
+                lines.iter().map(|l| format!("> {}", l)).collect::<Vec<_>>().join("\n")
+            }
+            Block::Params(params) => {
+                let mut out = String::from("# Parameters\n");
+                for param in params {
+                    let direction = param
+                        .direction
+                        .as_ref()
+                        .map(|d| format!(" ({})", d))
+                        .unwrap_or_default();
+                    let lines = inline_lines(&param.text);
+                    out += &format!("\n* `{}`{} - {}", param.name, direction, lines.join("\n  "));
+                }
+                out
+            }
+            Block::Returns(text) => returns(&mut returns_heading) + &inline_lines(text).join("\n"),
+            Block::Retvals(retvals) => {
+                let mut out = returns(&mut returns_heading);
+                out += "| Value | Description |\n|-------|-------------|";
+                for (value, text) in retvals {
+                    out += &format!("\n| `{}` | {} |", value, table_cell(text));
+                }
+                out
+            }
+            Block::SeeAlso(text) => {
+                let text = inline_lines(text).join("\n");
+                let text = name.replace_all(&text, |c: &regex::Captures| match c.name("name") {
+                    Some(name) if !text[name.end()..].starts_with('(') => format!("[{}]", name.as_str()),
+                    _ => c[0].to_string(),
+                });
+                format!("See also {}", text)
+            }
+        };
+        parts.push(part);
+    }
+
+    // Indent like a `///` comment.
+    let text = parts.join("\n\n");
+    let lines: Vec<_> = text
+        .lines()
+        .map(|l| if l.is_empty() { String::new() } else { format!(" {}", l) })
+        .collect();
+    lines.join("\n")
+}
+
+/// The heading of the return value, before the first `@return` or `@retval`.
+fn returns(heading: &mut bool) -> String {
+    if std::mem::replace(heading, true) {
+        String::new()
+    } else {
+        String::from("# Returns\n\n")
+    }
+}
+
+fn parse(comment: &str) -> Vec<Block> {
+    let mut blocks: Vec<Block> = Vec::new();
+    // The block continuation lines belong to, if any.
+    let mut current: Option<usize> = None;
+    let mut code: Option<Vec<String>> = None;
+    let mut first = true;
+
+    for raw in comment.lines() {
+        let mut line = raw.trim();
+        // Comments that clang merged with the one before them.
+        if line == "/" {
+            continue;
+        }
+        for marker in ["/**", "/*!", "///", "//!"] {
+            line = line.strip_prefix(marker).unwrap_or(line).trim_start();
+        }
+        line = line.strip_suffix("*/").unwrap_or(line).trim_end();
+        // Documentation of the member before the comment.
+        if first && !line.is_empty() {
+            line = line.strip_prefix("<").unwrap_or(line).trim_start();
+            first = false;
+        }
+
+        if let Some(lines) = &mut code {
+            if matches!(line, "@endcode" | "\\endcode") {
+                blocks.push(Block::Code(code.take().unwrap()));
+                current = None;
+            } else {
+                lines.push(raw.trim_end().to_string());
+            }
+            continue;
+        }
+
+        if line.is_empty() {
+            current = None;
+            continue;
+        }
+
+        let Some((command, rest)) = block_command(line) else {
+            match current.and_then(|i| blocks[i].text()) {
+                Some(text) => text.push(line.to_string()),
+                None => {
+                    blocks.push(Block::Paragraph(vec![line.to_string()]));
+                    current = Some(blocks.len() - 1);
+                }
+            }
+            continue;
+        };
+
+        let text = if rest.is_empty() {
+            vec![]
+        } else {
+            vec![rest.to_string()]
+        };
+        current = match command {
+            "brief" | "short" | "details" => push(&mut blocks, Block::Paragraph(text)),
+            "name" => {
+                blocks.push(Block::Heading(rest.to_string()));
+                None
+            }
+            "code" => {
+                code = Some(Vec::new());
+                None
+            }
+            "note" | "remark" | "remarks" => push(&mut blocks, Block::Note("Note", text)),
+            "warning" | "attention" => push(&mut blocks, Block::Note("Warning", text)),
+            "deprecated" => push(&mut blocks, Block::Note("Deprecated", text)),
+            "return" | "returns" | "result" => push(&mut blocks, Block::Returns(text)),
+            "sa" | "see" => push(&mut blocks, Block::SeeAlso(text)),
+            "param" | "tparam" => {
+                let (direction, rest) = match rest.strip_prefix('[').and_then(|r| r.split_once(']')) {
+                    Some((direction, rest)) => (Some(direction.replace(' ', "")), rest.trim_start()),
+                    None => (None, rest),
+                };
+                let (name, text) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+                let param = Param {
+                    name: name.to_string(),
+                    direction,
+                    text: vec![text.trim().to_string()],
+                };
+                match blocks.iter().position(|b| matches!(b, Block::Params(_))) {
+                    Some(i) => {
+                        if let Block::Params(params) = &mut blocks[i] {
+                            params.push(param);
+                        }
+                        Some(i)
+                    }
+                    None => push(&mut blocks, Block::Params(vec![param])),
+                }
+            }
+            "retval" => {
+                let (value, text) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+                let retval = (value.to_string(), vec![text.trim().to_string()]);
+                match blocks.last_mut() {
+                    Some(Block::Retvals(retvals)) => {
+                        retvals.push(retval);
+                        Some(blocks.len() - 1)
+                    }
+                    _ => push(&mut blocks, Block::Retvals(vec![retval])),
+                }
+            }
+            // Grouping, which has no equivalent in rustdoc.
+            _ => None,
+        };
+    }
+    if let Some(lines) = code {
+        blocks.push(Block::Code(lines));
+    }
+
+    // Commands without text on their own line leave empty lines behind.
+    blocks.retain_mut(|block| match block {
+        Block::Paragraph(text) | Block::Note(_, text) | Block::Returns(text) | Block::SeeAlso(text) => {
+            text.retain(|l| !l.is_empty());
+            !text.is_empty()
+        }
+        Block::Params(params) => {
+            params.iter_mut().for_each(|p| p.text.retain(|l| !l.is_empty()));
+            true
+        }
+        Block::Retvals(retvals) => {
+            retvals.iter_mut().for_each(|(_, text)| text.retain(|l| !l.is_empty()));
+            true
+        }
+        Block::Heading(_) | Block::Code(_) => true,
+    });
+    blocks
+}
+
+fn push(blocks: &mut Vec<Block>, block: Block) -> Option<usize> {
+    blocks.push(block);
+    Some(blocks.len() - 1)
+}
+
+/// The block command `line` starts with and the rest of the line.
+fn block_command(line: &str) -> Option<(&str, &str)> {
+    let rest = line.strip_prefix(['@', '\\'])?;
+    let end = match rest.chars().next()? {
+        '{' | '}' => 1,
+        _ => rest.find(|c: char| !c.is_ascii_alphabetic()).unwrap_or(rest.len()),
+    };
+    let command = &rest[..end];
+    BLOCK_COMMANDS.contains(&command).then(|| (command, rest[end..].trim()))
+}
+
+/// Convert the inline commands of `text`, which may have their argument on
+/// the next line.
+fn inline_lines(text: &[String]) -> Vec<String> {
+    inline(&text.join("\n")).lines().map(String::from).collect()
+}
+
+/// Join `text` into one line for a table cell, with its list items on lines of their own.
+fn table_cell(text: &[String]) -> String {
+    let mut cell = String::new();
+    for line in inline_lines(text) {
+        if line.starts_with("- ") || line.starts_with("* ") {
+            cell += "<br>";
+        } else if !cell.is_empty() {
+            cell += " ";
+        }
+        cell += &line;
+    }
+    cell.replace('|', "\\|")
+}
+
+/// Remove the indentation common to all lines.
+fn dedent(lines: &[String]) -> Vec<String> {
+    let indent = lines
+        .iter()
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| l.len() - l.trim_start().len())
+        .min()
+        .unwrap_or(0);
+    lines
+        .iter()
+        .map(|l| l.get(indent..).unwrap_or("").to_string())
+        .collect()
+}
+
+/// Convert the inline commands of `text`, and escape what Markdown would
+/// otherwise take for links or HTML.
+fn inline(text: &str) -> String {
+    let mut out = String::new();
+    let mut rest = text;
+    while let Some(c) = rest.chars().next() {
+        match c {
+            '`' => {
+                let end = rest[1..].find('`').map_or(rest.len(), |i| i + 2);
+                out += &rest[..end];
+                rest = &rest[end..];
+                continue;
+            }
+            '@' | '\\' if rest[1..].starts_with(|c: char| c.is_ascii_alphabetic()) => {
+                let end = rest[1..]
+                    .find(|c: char| !c.is_ascii_alphabetic())
+                    .map_or(rest.len(), |i| i + 1);
+                let (word, after) = next_word(&rest[end..]);
+                let converted = match &rest[1..end] {
+                    "ref" | "link" if !word.is_empty() => {
+                        let target_end = word
+                            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == ':'))
+                            .unwrap_or(word.len());
+                        let mut target_end = target_end;
+                        if word[target_end..].starts_with("()") {
+                            target_end += 2;
+                        }
+                        let (target, trailing) = word.split_at(target_end);
+                        Some(format!("[`{}`]{}", target, escape(trailing)))
+                    }
+                    "c" | "p" if !word.is_empty() => Some(format!("`{}`", word)),
+                    "a" | "e" | "em" if !word.is_empty() => Some(format!("*{}*", escape(word))),
+                    "b" if !word.is_empty() => Some(format!("**{}**", escape(word))),
+                    _ => None,
+                };
+                match converted {
+                    Some(converted) => {
+                        out += &converted;
+                        rest = after;
+                    }
+                    None => {
+                        out += &rest[..end];
+                        rest = &rest[end..];
+                    }
+                }
+                continue;
+            }
+            _ => out += &escape(&rest[..c.len_utf8()]),
+        }
+        rest = &rest[c.len_utf8()..];
+    }
+    out
+}
+
+/// The word at the start of `text` after whitespace, without trailing
+/// punctuation, and the text after it.
+fn next_word(text: &str) -> (&str, &str) {
+    let start = text.len() - text.trim_start().len();
+    let text_start = &text[start..];
+    let mut end = text_start.find(char::is_whitespace).unwrap_or(text_start.len());
+    while end > 0 && text_start[..end].ends_with(['.', ',', ';', ':', '!', '?', ')']) {
+        // Keep the parentheses of a function call.
+        if text_start[..end].ends_with("()") {
+            break;
+        }
+        end -= 1;
+    }
+    (&text_start[..end], &text_start[end..])
+}
+
+fn escape(text: &str) -> String {
+    let mut out = String::new();
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '[' | ']' => out.push('\\'),
+            '<' if chars.peek().is_some_and(|c| c.is_ascii_alphabetic() || *c == '/') => out.push('\\'),
+            _ => {}
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// Turn links to items that were not generated into code spans, and point
+/// links to C enumerators at their constants, which bindgen names
+/// `<enum>_<enumerator>`. The shortcut links made for `@sa` become plain text
+/// unless they resolve.
+pub fn resolve_links(source: &str) -> String {
+    let item = regex::Regex::new(r"(?m)^\s*pub (?:fn|const|type|struct|union|static(?: mut)?) (\w+)").unwrap();
+    let items: HashSet<_> = item.captures_iter(source).map(|c| c[1].to_string()).collect();
+
+    let link = regex::Regex::new(r"\[`(?P<path>\w+(?:::\w+)*)(?P<call>\(\))?`\]").unwrap();
+    let source = link.replace_all(source, |c: &regex::Captures| {
+        let (path, call) = (&c["path"], c.name("call").map_or("", |m| m.as_str()));
+        resolve(&items, path, call).unwrap_or_else(|| format!("`{}{}`", path, call))
+    });
+
+    // Escaped brackets are skipped, only `@sa` leaves them unescaped.
+    let see_also = regex::Regex::new(r"\\.|\[(?P<name>\w+)(?P<call>\(\))?\]").unwrap();
+    source
+        .split_inclusive('\n')
+        .map(|line| {
+            if !line.trim_start().starts_with("#[doc") {
+                return line.into();
+            }
+            see_also.replace_all(line, |c: &regex::Captures| match c.name("name") {
+                Some(name) => {
+                    let call = c.name("call").map_or("", |m| m.as_str());
+                    resolve(&items, name.as_str(), call).unwrap_or_else(|| format!("{}{}", name.as_str(), call))
+                }
+                None => c[0].to_string(),
+            })
+        })
+        .collect()
+}
+
+/// The intra-doc link to `path` among `items`, if there is one.
+fn resolve(items: &HashSet<String>, path: &str, call: &str) -> Option<String> {
+    let base = path.split("::").next().unwrap();
+    if items.contains(base) {
+        return Some(format!("[`{}{}`]", path, call));
+    }
+    let suffix = format!("_{}", path);
+    let mut constants = items.iter().filter(|i| {
+        i.strip_suffix(suffix.as_str())
+            .is_some_and(|enumeration| items.contains(enumeration))
+    });
+    match (constants.next(), constants.next()) {
+        (Some(constant), None) if !path.contains("::") => Some(format!("[`{}`]({})", path, constant)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    /// Each `snapshots/*.txt` holds a comment as bindgen passes it, and the
+    /// `.md` next to it the expected rustdoc. Set `UPDATE_SNAPSHOTS` to write
+    /// the `.md` files from the output instead.
+    #[test]
+    fn snapshots() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("snapshots");
+        let update = std::env::var_os("UPDATE_SNAPSHOTS").is_some();
+        let mut failed = Vec::new();
+        for entry in std::fs::read_dir(&dir).unwrap() {
+            let input = entry.unwrap().path();
+            if input.extension() != Some("txt".as_ref()) {
+                continue;
+            }
+            let expected_path = input.with_extension("md");
+            let actual = to_rustdoc(&std::fs::read_to_string(&input).unwrap()) + "\n";
+            if update {
+                std::fs::write(&expected_path, &actual).unwrap();
+            } else if std::fs::read_to_string(&expected_path).ok().as_deref() != Some(actual.as_str()) {
+                eprintln!("{}:\n{}", expected_path.display(), actual);
+                failed.push(expected_path);
+            }
+        }
+        assert!(failed.is_empty(), "snapshots differ: {:?}", failed);
+    }
+
+    #[test]
+    fn resolves_links() {
+        let source = "\
+#[doc = \" See [`sdc_enable()`], [`sdc_cfg_t::adv_count`] and [`SDC_CFG_TYPE_NONE`], not [`k_work`].\"]
+#[doc = \" See also [sdc_enable] [SDC_CFG_TYPE_NONE] [the] [k_work()], not \\\\[sdc_enable\\\\].\"]
+pub fn sdc_enable() -> i32;
+pub union sdc_cfg_t {}
+pub type sdc_cfg_type = u32;
+pub const sdc_cfg_type_SDC_CFG_TYPE_NONE: sdc_cfg_type = 0;
+";
+        let resolved = resolve_links(source);
+        assert!(resolved.starts_with(
+            "#[doc = \" See [`sdc_enable()`], [`sdc_cfg_t::adv_count`] and \
+             [`SDC_CFG_TYPE_NONE`](sdc_cfg_type_SDC_CFG_TYPE_NONE), not `k_work`.\"]\n\
+             #[doc = \" See also [`sdc_enable`] [`SDC_CFG_TYPE_NONE`](sdc_cfg_type_SDC_CFG_TYPE_NONE) the k_work(), \
+             not \\\\[sdc_enable\\\\].\"]\n"
+        ));
+    }
+}
@@ -1,80 +1,177 @@
 //! Calls out to bindgen to generate a Rust crate from the Nordic header
 //! files.
+//!
+//! The headers are parsed for `--mcpu` and `--float-abi`, by default
+//! `cortex-m4` and `soft`, matching one of the library builds in nrf-sdc-sys.
+//! `--dppi` parses them for chips with DPPI instead of PPI, such as the
+//! nRF5340 network core.
+//!
+//! Next to the bindings, `nrfxlib.lock` records the nrfxlib release and the
+//! checksums of its headers, which the nrf-sdc-sys build script verifies.
+//! Pass the release archive with `--archive` to record its checksum as well.
+//!
+//! `--check` compares the committed bindings with the ones generated from the
+//! headers instead of writing them, lists the functions, structs, constants and
+//! types that differ, and fails if there are any.
+//!
+//! The bindgen configuration, with the allow-list of the generated items, is
+//! shared with the `bindgen` feature of nrf-sdc-sys.
+//!
+//! The Doxygen comments of the headers are converted to rustdoc, see the
+//! `doxygen` module.
+
+mod api;
+#[path = "../../nrf-sdc-sys/bindgen/builder.rs"]
+mod builder;
+mod doxygen;
+
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
+
+/// Cores and float ABIs the libraries are built for.
+const MCPUS: &[&str] = &["cortex-m4", "cortex-m33", "cortex-m33+nodsp"];
+const FLOAT_ABIS: &[&str] = &["soft", "softfp", "hard"];
+
+fn usage() -> ! {
+    eprintln!("usage: nrf-sdc-gen [--mcpu <mcpu>] [--float-abi <abi>] [--dppi] [--archive <path>] [--check]");
+    eprintln!("  --mcpu       one of {}", MCPUS.join(", "));
+    eprintln!("  --float-abi  one of {}", FLOAT_ABIS.join(", "));
+    eprintln!("  --dppi       the chip has DPPI instead of PPI");
+    eprintln!("  --archive    the nrfxlib release archive, sdk-nrfxlib-<version>.tar.gz");
+    eprintln!("  --check      compare the committed bindings with the headers instead of writing them");
+    std::process::exit(1);
+}
 
 fn main() {
-    use std::path::PathBuf;
     let nrfxlib_path = "../sdk-nrfxlib";
-    // The bindgen::Builder is the main entry point
-    // to bindgen, and lets you build up options for
-    // the resulting bindings.
-    let bindings = bindgen::Builder::default()
-        // The input header we would like to generate
-        // bindings for.
-        .header("wrapper.h")
-        // Our own headers
-        .clang_arg(format!("-I{}", nrfxlib_path))
-        // Point to Nordic headers
-        .clang_arg(format!("-I./include"))
-        .clang_arg(format!("-DPPI_PRESENT"))
-        .clang_arg(format!("-I{}/softdevice_controller/include", nrfxlib_path))
-        .clang_arg(format!("-I{}/mpsl/include", nrfxlib_path))
-        // Point to our special local headers
-        // Add extra paths that the C files assume are searched
-        // Disable standard includes (they belong to the host)
-        .clang_arg("-nostdinc")
-        // Set the target
-        .clang_arg("-target")
-        .clang_arg("arm")
-        .clang_arg("-mcpu=cortex-m4")
-        // Use softfp
-        .clang_arg("-mfloat-abi=soft")
-        // We're no_std
-        .use_core()
-        .ctypes_prefix("crate::ctypes")
-        // Include only the useful stuff
-        // .allowlist_function("nrf_.*")
-        // .allowlist_function("sdc.*")
-        // .allowlist_function("mpsl.*")
-        //.allowlist_type("nrf_.*")
-        //.allowlist_type("sdc.*")
-        // .allowlist_var("NRF_.*")
-        // .allowlist_var("SDC_.*")
-        // .allowlist_var("MPSL_.*")
-        //.allowlist_var("BSD_.*")
-        //.allowlist_var("OCRYPTO_.*")
-        // Format the output
-        .formatter(bindgen::Formatter::Rustfmt)
-        // Finish the builder and generate the bindings.
-        .generate()
-        // Unwrap the Result and panic on failure.
-        .expect("Unable to generate bindings");
-
-    // Write the bindings to the $OUT_DIR/bindings.rs file.
-    let mut rust_source = bindings.to_string();
-
-    // Munge Doxygen comments into something Rustdoc can handle
-    rust_source = rust_source.replace("#[doc = \"@{*/\"]", "");
-    let re = regex::Regex::new("\"   \\s+- ").unwrap();
-    rust_source = re.replace_all(&rust_source, "\" * ").into();
-    let re = regex::Regex::new(r"\s*@param\s+(?P<var>[A-Za-z0-9_]+)\s+").unwrap();
-    rust_source = re.replace_all(&rust_source, " * `$var` - ").into();
-    let re = regex::Regex::new(r"\s*@param\[(out|in|inout|in,out)\](\\t|\s+)(?P<var>[A-Za-z0-9_]+)\s+").unwrap();
-    rust_source = re.replace_all(&rust_source, " * `$var` - ").into();
-    let re = regex::Regex::new(r"@[cp]\s+(?P<var>[A-Za-z0-9_\(\)]+)").unwrap();
-    rust_source = re.replace_all(&rust_source, " * `$var` - ").into();
-    let re = regex::Regex::new(r"\\\\[cp]\s+(?P<var>[A-Za-z0-9_\(\)]+)").unwrap();
-    rust_source = re.replace_all(&rust_source, "`$var`").into();
-    let re = regex::Regex::new(r"\\\\ref\s+(?P<var>[A-Za-z0-9_\(\)]+)").unwrap();
-    rust_source = re.replace_all(&rust_source, "`$var`").into();
-    rust_source = rust_source.replace("\" @remark", "\" NB: ");
-    rust_source = rust_source.replace("\"@brief", "\"");
-    rust_source = rust_source.replace("\" @brief", "\" ");
-    rust_source = rust_source.replace("\"@detail", "\"");
-    rust_source = rust_source.replace("\" @detail", "\" ");
-    rust_source = rust_source.replace("@name ", "# ");
-    rust_source = rust_source.replace("@return ", "Returns ");
-    rust_source = rust_source.replace("@retval ", "Returns ");
+
+    let mut mcpu = String::from("cortex-m4");
+    let mut float_abi = String::from("soft");
+    let mut dppi = false;
+    let mut archive = None;
+    let mut check = false;
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--mcpu" => mcpu = args.next().unwrap_or_else(|| usage()),
+            "--float-abi" => float_abi = args.next().unwrap_or_else(|| usage()),
+            "--dppi" => dppi = true,
+            "--check" => check = true,
+            "--archive" => archive = Some(PathBuf::from(args.next().unwrap_or_else(|| usage()))),
+            _ => usage(),
+        }
+    }
+    if !MCPUS.contains(&mcpu.as_str()) || !FLOAT_ABIS.contains(&float_abi.as_str()) {
+        usage();
+    }
+    // There is no FPU without the DSP extension.
+    if mcpu == "cortex-m33+nodsp" && float_abi != "soft" {
+        eprintln!("cortex-m33+nodsp only supports the soft float ABI");
+        std::process::exit(1);
+    }
+
+    let bindings = builder::builder(
+        Path::new("../nrf-sdc-sys/bindgen"),
+        Path::new(nrfxlib_path),
+        &mcpu,
+        &float_abi,
+        dppi,
+    )
+    // Convert the Doxygen comments to rustdoc
+    .parse_callbacks(Box::new(doxygen::Callbacks))
+    // Format the output
+    .formatter(bindgen::Formatter::Rustfmt)
+    // Finish the builder and generate the bindings.
+    .generate()
+    // Unwrap the Result and panic on failure.
+    .expect("Unable to generate bindings");
+
+    let rust_source = doxygen::resolve_links(&bindings.to_string());
 
     let bindings_out_path = PathBuf::from("../nrf-sdc-sys/src").join("bindings.rs");
+    if check {
+        check_bindings(&bindings_out_path, &rust_source);
+    }
     std::fs::write(bindings_out_path, rust_source).expect("Couldn't write updated bindgen output");
+
+    write_lock(Path::new(nrfxlib_path), archive.as_deref());
+}
+
+/// Compare the committed bindings with `generated` and exit, with an error if
+/// they differ.
+fn check_bindings(committed_path: &Path, generated: &str) -> ! {
+    let committed = std::fs::read_to_string(committed_path).expect("Couldn't read the committed bindings");
+    if committed == generated {
+        println!("{} is up to date", committed_path.display());
+        std::process::exit(0);
+    }
+
+    let parse = |source: &str| api::Api::parse(source).expect("Couldn't parse the bindings");
+    let diff = parse(&committed).diff(&parse(generated));
+    if diff.is_empty() {
+        println!(
+            "{} differs from the headers only in doc comments or formatting",
+            committed_path.display()
+        );
+    } else {
+        println!("{} differs from the headers:\n\n{}", committed_path.display(), diff);
+    }
+    std::process::exit(1);
+}
+
+fn sha256(bytes: &[u8]) -> String {
+    Sha256::digest(bytes).iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Paths of the headers under `dir`, relative to `root`.
+fn headers(root: &Path, dir: &Path, out: &mut Vec<PathBuf>) {
+    for entry in std::fs::read_dir(dir).expect("Couldn't read the nrfxlib headers") {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            headers(root, &path, out);
+        } else if path.extension().is_some_and(|e| e == "h") {
+            out.push(path.strip_prefix(root).unwrap().to_path_buf());
+        }
+    }
+}
+
+/// Record the nrfxlib release and its headers in `nrfxlib.lock`.
+fn write_lock(nrfxlib_path: &Path, archive: Option<&Path>) {
+    let version = std::process::Command::new("git")
+        .arg("-C")
+        .arg(nrfxlib_path)
+        .args(["describe", "--tags", "--exact-match"])
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+        .map(|o| String::from_utf8(o.stdout).unwrap().trim().to_string());
+
+    let mut lock = String::from("# nrfxlib the bindings were generated from, written by nrf-sdc-gen.\n");
+    match &version {
+        Some(version) => lock += &format!("version {}\n", version),
+        None => eprintln!("nrfxlib is not at a release tag, the lock names no version"),
+    }
+    if let Some(archive) = archive {
+        let name = archive.file_name().unwrap().to_string_lossy();
+        if version.as_ref().map(|v| format!("sdk-nrfxlib-{}.tar.gz", v)).as_deref() != Some(name.as_ref()) {
+            eprintln!("{} is not the archive of the checked out release", archive.display());
+            std::process::exit(1);
+        }
+        let bytes = std::fs::read(archive).expect("Couldn't read the release archive");
+        lock += &format!("archive {}\n", sha256(&bytes));
+    }
+
+    let mut paths = Vec::new();
+    for dir in ["softdevice_controller/include", "mpsl/include"] {
+        headers(nrfxlib_path, &nrfxlib_path.join(dir), &mut paths);
+    }
+    paths.sort();
+    for path in paths {
+        let bytes = std::fs::read(nrfxlib_path.join(&path)).unwrap();
+        // Forward slashes, so the lock is the same on every host.
+        let path = path.to_string_lossy().replace('\\', "/");
+        lock += &format!("header {} {}\n", sha256(&bytes), path);
+    }
+
+    std::fs::write("../nrf-sdc-sys/nrfxlib.lock", lock).expect("Couldn't write nrfxlib.lock");
 }
@@ -0,0 +1,43 @@
+//! The bindgen configuration shared by the `bindgen` feature of the nrf-sdc-sys
+//! build script and nrf-sdc-gen, so both generate the same bindings.
+
+use std::path::Path;
+
+/// Functions, types and constants of the SoftDevice Controller and MPSL APIs.
+/// Everything else in the headers, such as the C library, is left out unless
+/// one of these items refers to it.
+pub const ALLOWLIST: &[&str] = &["sdc_.*", "mpsl_.*", "HCI_.*", "SDC_.*", "MPSL_.*", "NRF_E.*"];
+
+/// A builder for the headers of `nrfxlib_path`, parsed for `mcpu` and
+/// `float_abi` as in `-mcpu` and `-mfloat-abi`, and for chips with DPPI instead
+/// of PPI if `dppi` is set. `dir` is the directory of this file, with
+/// `wrapper.h` and the stand-in C library headers.
+pub fn builder(dir: &Path, nrfxlib_path: &Path, mcpu: &str, float_abi: &str, dppi: bool) -> bindgen::Builder {
+    let mut builder = bindgen::Builder::default()
+        .header(dir.join("wrapper.h").display().to_string())
+        .clang_arg(format!("-I{}", nrfxlib_path.display()))
+        // Stand-ins for the C library headers
+        .clang_arg(format!("-I{}", dir.join("include").display()))
+        .clang_arg(if dppi { "-DDPPI_PRESENT" } else { "-DPPI_PRESENT" })
+        .clang_arg(format!(
+            "-I{}",
+            nrfxlib_path.join("softdevice_controller/include").display()
+        ))
+        .clang_arg(format!("-I{}", nrfxlib_path.join("mpsl/include").display()))
+        // Disable standard includes (they belong to the host)
+        .clang_arg("-nostdinc")
+        .clang_arg("-target")
+        .clang_arg("arm")
+        .clang_arg(format!("-mcpu={}", mcpu))
+        .clang_arg(format!("-mfloat-abi={}", float_abi))
+        // We're no_std
+        .use_core()
+        .ctypes_prefix("crate::ctypes");
+    for pattern in ALLOWLIST {
+        builder = builder
+            .allowlist_function(pattern)
+            .allowlist_type(pattern)
+            .allowlist_var(pattern);
+    }
+    builder
+}
@@ -1,116 +1,468 @@
 //! Calls out to bindgen to generate a Rust crate from the Nordic header
 //! files.
 
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use sha2::{Digest, Sha256};
+
+#[cfg(feature = "bindgen")]
+#[path = "bindgen/builder.rs"]
+mod builder;
+
 fn main() {
-    use std::env;
-    use std::path::{Path, PathBuf};
-    let nrfxlib_path = "../sdk-nrfxlib";
-
-    let libsoftdevice_controller_original_path = Path::new(&nrfxlib_path)
-        .join("softdevice_controller/lib/cortex-m4/soft-float/libsoftdevice_controller_multirole.a");
-    let libsoftdevice_controller_changed_path =
-        PathBuf::from(env::var("OUT_DIR").unwrap()).join("libsoftdevice_controller.a");
-    // The softdevice_controller library now has compressed headers, but Rust cannot deal with that.
-    // If the appropriate features is active, we're gonna strip it or decompress it.
-    #[cfg(feature = "arm-none-eabi-objcopy")]
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=nrfxlib.lock");
+    println!("cargo:rerun-if-env-changed=NRFXLIB_PATH");
+    println!("cargo:rerun-if-env-changed=NRFXLIB_CACHE_DIR");
+
+    let variant = match (
+        cfg!(feature = "peripheral"),
+        cfg!(feature = "central"),
+        cfg!(feature = "multirole"),
+    ) {
+        (true, false, false) => "peripheral",
+        (false, true, false) => "central",
+        (false, false, true) => "multirole",
+        _ => panic!(
+            "Exactly one of the `peripheral`, `central` and `multirole` features must be enabled. \
+             Disable the default features to select `peripheral` or `central`."
+        ),
+    };
+
+    // Host builds, such as for the unit tests of nrf-sdc, use the committed
+    // bindings and link no library.
+    if !env::var("TARGET").unwrap().starts_with("thumb") && !cfg!(feature = "bindgen") {
+        return;
+    }
+    let lock = Lock::read();
+    let nrfxlib_path = nrfxlib_path(&lock);
+    // Generated bindings match whatever headers there are.
+    if !cfg!(feature = "bindgen") {
+        check_headers(&nrfxlib_path, &lock);
+    }
+
+    let lib_dir = lib_dir();
+    check_chip(lib_dir);
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    #[cfg(feature = "bindgen")]
+    generate_bindings(&nrfxlib_path, lib_dir, &out_dir);
+
+    let libs = [
+        (
+            format!(
+                "softdevice_controller/lib/{}/libsoftdevice_controller_{}.a",
+                lib_dir, variant
+            ),
+            "softdevice_controller",
+        ),
+        (format!("mpsl/lib/{}/libmpsl.a", lib_dir), "mpsl"),
+    ];
+    for (original, name) in libs {
+        let original = nrfxlib_path.join(original);
+        println!("cargo:rerun-if-changed={}", original.display());
+        let input = fs::read(&original).unwrap_or_else(|e| panic!("Could not read {}: {}", original.display(), e));
+        let output =
+            preprocess_archive(&input).unwrap_or_else(|e| panic!("Could not process {}: {}", original.display(), e));
+        fs::write(out_dir.join(format!("lib{}.a", name)), output).unwrap();
+        println!("cargo:rustc-link-lib=static={}", name);
+    }
+    // Make sure we link against the libraries
+    println!("cargo:rustc-link-search={}", out_dir.display());
+}
+
+/// Core and float ABI of each library build, with the target and features selecting it.
+const LIB_DIRS: &[(&str, bool, bool, &str)] = &[
+    // (target, softfp, cortex-m33-nodsp, directory)
+    ("thumbv7em-none-eabi", false, false, "cortex-m4/soft-float"),
+    ("thumbv7em-none-eabi", true, false, "cortex-m4/softfp-float"),
+    ("thumbv7em-none-eabihf", false, false, "cortex-m4/hard-float"),
+    ("thumbv8m.main-none-eabi", false, false, "cortex-m33/soft-float"),
+    ("thumbv8m.main-none-eabi", true, false, "cortex-m33/softfp-float"),
+    ("thumbv8m.main-none-eabihf", false, false, "cortex-m33/hard-float"),
+    ("thumbv8m.main-none-eabi", false, true, "cortex-m33+nodsp/soft-float"),
+];
+
+/// The library directory matching the Cargo target and the `softfp` and
+/// `cortex-m33-nodsp` features.
+fn lib_dir() -> &'static str {
+    let target = env::var("TARGET").unwrap();
+    let softfp = cfg!(feature = "softfp");
+    let nodsp = cfg!(feature = "cortex-m33-nodsp");
+    if let Some((_, _, _, dir)) = LIB_DIRS
+        .iter()
+        .find(|(t, s, n, _)| *t == target && *s == softfp && *n == nodsp)
     {
-        // We assume the arm-none-eabi-objcopy comes from the official arm website.
-        let child = std::process::Command::new("arm-none-eabi-objcopy")
-            .arg("--decompress-debug-sections")
-            .arg(&libsoftdevice_controller_original_path)
-            .arg(&libsoftdevice_controller_changed_path)
-            .spawn()
-            .expect("Could not start `arm-none-eabi-objcopy`. Is it installed and available in your path?");
-
-        let child_result = child.wait_with_output().unwrap();
-        if !child_result.status.success() {
-            panic!("Something went wrong with `arm-none-eabi-objcopy`.");
+        return dir;
+    }
+
+    let mut supported = String::new();
+    for (target, softfp, nodsp, dir) in LIB_DIRS {
+        let features = match (softfp, nodsp) {
+            (true, _) => " with the `softfp` feature",
+            (_, true) => " with the `cortex-m33-nodsp` feature",
+            _ => "",
+        };
+        supported += &format!("\n  {}{} -> {}", target, features, dir);
+    }
+    panic!(
+        "No SoftDevice Controller library for target `{}` (softfp: {}, cortex-m33-nodsp: {}). Supported combinations:{}",
+        target, softfp, nodsp, supported
+    );
+}
+
+/// Generate the bindings to `OUT_DIR` for the core and float ABI of `lib_dir`.
+#[cfg(feature = "bindgen")]
+fn generate_bindings(nrfxlib_path: &Path, lib_dir: &str, out_dir: &Path) {
+    let (core, float) = lib_dir.split_once('/').unwrap();
+    let float_abi = float.strip_suffix("-float").unwrap();
+    // The chips with a Cortex-M33 have DPPI instead of PPI.
+    let dppi = core.starts_with("cortex-m33");
+
+    let dir = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("bindgen");
+    println!("cargo:rerun-if-changed={}", dir.display());
+    builder::builder(&dir, nrfxlib_path, core, float_abi, dppi)
+        .generate()
+        .expect("Unable to generate bindings")
+        .write_to_file(out_dir.join("bindings.rs"))
+        .expect("Couldn't write bindings");
+}
+
+/// Check that the library directory matches the core of the chip feature, if any.
+fn check_chip(lib_dir: &str) {
+    let chips = [
+        ("nrf52805", cfg!(feature = "nrf52805"), "cortex-m4"),
+        ("nrf52810", cfg!(feature = "nrf52810"), "cortex-m4"),
+        ("nrf52811", cfg!(feature = "nrf52811"), "cortex-m4"),
+        ("nrf52820", cfg!(feature = "nrf52820"), "cortex-m4"),
+        ("nrf52832", cfg!(feature = "nrf52832"), "cortex-m4"),
+        ("nrf52833", cfg!(feature = "nrf52833"), "cortex-m4"),
+        ("nrf52840", cfg!(feature = "nrf52840"), "cortex-m4"),
+        ("nrf5340-net", cfg!(feature = "nrf5340-net"), "cortex-m33+nodsp"),
+        ("nrf54l15", cfg!(feature = "nrf54l15"), "cortex-m33"),
+    ];
+    let mut enabled = chips.iter().filter(|(_, enabled, _)| *enabled);
+    let Some((chip, _, core)) = enabled.next() else {
+        return;
+    };
+    if let Some((other, _, _)) = enabled.next() {
+        panic!(
+            "At most one chip feature may be enabled, got `{}` and `{}`.",
+            chip, other
+        );
+    }
+    if lib_dir.split('/').next() != Some(*core) {
+        panic!(
+            "The `{}` feature needs a {} library, but the target selects {}.",
+            chip, core, lib_dir
+        );
+    }
+}
+
+/// The nrfxlib the committed bindings were generated from, as recorded in
+/// `nrfxlib.lock` by nrf-sdc-gen.
+#[derive(Default)]
+struct Lock {
+    /// Release tag, such as `v2.6.0`.
+    version: Option<String>,
+    /// SHA-256 of the release archive.
+    archive: Option<String>,
+    /// SHA-256 of each header, by path relative to nrfxlib.
+    headers: Vec<(String, String)>,
+}
+
+impl Lock {
+    fn read() -> Self {
+        let path = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("nrfxlib.lock");
+        let text = fs::read_to_string(&path).unwrap_or_else(|e| {
+            panic!(
+                "Could not read {}: {}. Regenerate the bindings and the lock with nrf-sdc-gen.",
+                path.display(),
+                e
+            )
+        });
+
+        let mut lock = Self::default();
+        for line in text.lines().map(str::trim) {
+            let mut words = line.split_whitespace();
+            match (words.next(), words.next(), words.next()) {
+                (None, _, _) => {}
+                (Some(comment), _, _) if comment.starts_with('#') => {}
+                (Some("version"), Some(version), None) => lock.version = Some(version.into()),
+                (Some("archive"), Some(sha256), None) => lock.archive = Some(sha256.into()),
+                (Some("header"), Some(sha256), Some(header)) => lock.headers.push((header.into(), sha256.into())),
+                _ => panic!("Malformed line in {}: `{}`", path.display(), line),
+            }
         }
+        lock
     }
+}
 
-    #[cfg(feature = "llvm-objcopy")]
-    {
-        // We assume the llvm-objcopy comes from the rustup llvm-tools.
-        // This cannot do decompression, so we'll just strip the debug sections
-
-        let tool_error = "Could not find `llvm-objcopy`. Is it installed? Use `rustup component add llvm-tools` to install it or select the `arm-none-eabi-objcopy` feature if you have that tool installed.";
-        // It's not in our path, so we have to search for it
-        let path = llvm_tools::LlvmTools::new()
-            .expect(tool_error)
-            .tool(&llvm_tools::exe("llvm-objcopy"))
-            .expect(tool_error);
-
-        let child = std::process::Command::new(path)
-            .arg("--strip-debug")
-            .arg(&libsoftdevice_controller_original_path)
-            .arg(&libsoftdevice_controller_changed_path)
-            .spawn()
-            .expect(tool_error);
-
-        let child_result = child.wait_with_output().unwrap();
-        if !child_result.status.success() {
-            panic!("Something went wrong with `llvm-objcopy`.");
+/// Locate nrfxlib, in order: `NRFXLIB_PATH`, a copy vendored in the package,
+/// the submodule of the repository, or the release archive from `NRFXLIB_CACHE_DIR`.
+fn nrfxlib_path(lock: &Lock) -> PathBuf {
+    if let Ok(path) = env::var("NRFXLIB_PATH") {
+        let path = PathBuf::from(path);
+        if !path.join("softdevice_controller").is_dir() {
+            panic!("NRFXLIB_PATH `{}` is not an nrfxlib checkout.", path.display());
         }
+        return path;
     }
 
-    // Make sure we link against the libraries
-    println!(
-        "cargo:rustc-link-search={}",
-        libsoftdevice_controller_changed_path.parent().unwrap().display()
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    for path in [manifest_dir.join("sdk-nrfxlib"), manifest_dir.join("../sdk-nrfxlib")] {
+        if path.join("softdevice_controller").is_dir() {
+            println!("cargo:rerun-if-changed={}", path.display());
+            return path;
+        }
+    }
+
+    if let Ok(cache_dir) = env::var("NRFXLIB_CACHE_DIR") {
+        return extract_release(Path::new(&cache_dir), lock);
+    }
+
+    panic!(
+        "nrfxlib not found. Set NRFXLIB_PATH to an nrfxlib checkout, check out the sdk-nrfxlib submodule, \
+         or set NRFXLIB_CACHE_DIR to a directory with the release archive `sdk-nrfxlib-{}.tar.gz`.",
+        lock.version.as_deref().unwrap_or("<version>")
     );
-    println!("cargo:rustc-link-lib=static=softdevice_controller");
+}
+
+/// Verify the release archive in `cache_dir` and extract it to `OUT_DIR`.
+fn extract_release(cache_dir: &Path, lock: &Lock) -> PathBuf {
+    let (Some(version), Some(expected)) = (&lock.version, &lock.archive) else {
+        panic!("nrfxlib.lock does not name a release archive, set NRFXLIB_PATH instead.");
+    };
+    let archive = cache_dir.join(format!("sdk-nrfxlib-{}.tar.gz", version));
+    println!("cargo:rerun-if-changed={}", archive.display());
+    let bytes = fs::read(&archive).unwrap_or_else(|e| panic!("Could not read {}: {}", archive.display(), e));
+    let actual = sha256(&bytes);
+    if actual != *expected {
+        panic!(
+            "Checksum mismatch for {}: expected {}, got {}.",
+            archive.display(),
+            expected,
+            actual
+        );
+    }
 
-    // MPSL
-    let libmpsl_original_path = Path::new(&nrfxlib_path).join("mpsl/lib/cortex-m4/soft-float/libmpsl.a");
-    let libmpsl_changed_path = PathBuf::from(env::var("OUT_DIR").unwrap()).join("libmpsl.a");
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap()).join(format!("sdk-nrfxlib-{}", version));
+    if out_dir.exists() {
+        fs::remove_dir_all(&out_dir).unwrap();
+    }
+    tar::Archive::new(flate2::read::GzDecoder::new(&bytes[..]))
+        .unpack(&out_dir)
+        .unwrap_or_else(|e| panic!("Could not extract {}: {}", archive.display(), e));
 
-    // The softdevice_controller library now has compressed headers, but Rust cannot deal with that.
-    // If the appropriate features is active, we're gonna strip it or decompress it.
-    #[cfg(feature = "arm-none-eabi-objcopy")]
-    {
-        // We assume the arm-none-eabi-objcopy comes from the official arm website.
-        let child = std::process::Command::new("arm-none-eabi-objcopy")
-            .arg("--decompress-debug-sections")
-            .arg(&libmpsl_original_path)
-            .arg(&libmpsl_changed_path)
-            .spawn()
-            .expect("Could not start `arm-none-eabi-objcopy`. Is it installed and available in your path?");
-
-        let child_result = child.wait_with_output().unwrap();
-        if !child_result.status.success() {
-            panic!("Something went wrong with `arm-none-eabi-objcopy`.");
+    // Release archives have a single top level directory.
+    let mut entries = fs::read_dir(&out_dir).unwrap().map(|e| e.unwrap().path());
+    match (entries.next(), entries.next()) {
+        (Some(root), None) if root.is_dir() => root,
+        _ => panic!("Unexpected layout of {}.", archive.display()),
+    }
+}
+
+/// Check that the headers are the ones the bindings were generated from.
+fn check_headers(nrfxlib_path: &Path, lock: &Lock) {
+    let mut mismatches = Vec::new();
+    for (header, expected) in &lock.headers {
+        let actual = fs::read(nrfxlib_path.join(header)).ok().map(|bytes| sha256(&bytes));
+        if actual.as_ref() != Some(expected) {
+            mismatches.push(header.as_str());
         }
     }
+    if !mismatches.is_empty() {
+        panic!(
+            "The nrfxlib at {} differs from {}, which the bindings were generated from, in:\n  {}\n\
+             Use that version or regenerate the bindings with nrf-sdc-gen.",
+            nrfxlib_path.display(),
+            lock.version.as_deref().unwrap_or("the recorded version"),
+            mismatches.join("\n  ")
+        );
+    }
+}
 
-    #[cfg(feature = "llvm-objcopy")]
-    {
-        // We assume the llvm-objcopy comes from the rustup llvm-tools.
-        // This cannot do decompression, so we'll just strip the debug sections
-
-        let tool_error = "Could not find `llvm-objcopy`. Is it installed? Use `rustup component add llvm-tools` to install it or select the `arm-none-eabi-objcopy` feature if you have that tool installed.";
-        // It's not in our path, so we have to search for it
-        let path = llvm_tools::LlvmTools::new()
-            .expect(tool_error)
-            .tool(&llvm_tools::exe("llvm-objcopy"))
-            .expect(tool_error);
-
-        let child = std::process::Command::new(path)
-            .arg("--strip-debug")
-            .arg(&libmpsl_original_path)
-            .arg(&libmpsl_changed_path)
-            .spawn()
-            .expect(tool_error);
-
-        let child_result = child.wait_with_output().unwrap();
-        if !child_result.status.success() {
-            panic!("Something went wrong with `llvm-objcopy`.");
+fn sha256(bytes: &[u8]) -> String {
+    Sha256::digest(bytes).iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// The libraries have compressed debug sections, which the Rust linker cannot
+/// handle. Rewrite every object of the archive with its debug sections
+/// decompressed, or stripped with the `strip-debug` feature.
+fn preprocess_archive(input: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    let archive = object::read::archive::ArchiveFile::parse(input)?;
+    let mut members = Vec::new();
+    for member in archive.members() {
+        let member = member?;
+        let name = member.name().to_vec();
+        let data = member.data(input)?;
+        let data = match object::FileKind::parse(data) {
+            Ok(object::FileKind::Elf32) => preprocess_object(data)?,
+            _ => data.to_vec(),
+        };
+        members.push((name, data));
+    }
+    write_archive(&members)
+}
+
+fn preprocess_object(input: &[u8]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    use object::elf::{CompressionHeader32, FileHeader32, SHF_COMPRESSED, SHT_NOBITS, SHT_REL, SHT_RELA};
+    use object::read::elf::{FileHeader as _, SectionHeader as _};
+    use object::{CompressedData, CompressionFormat, Endianness};
+    use std::borrow::Cow;
+
+    let header = FileHeader32::<Endianness>::parse(input)?;
+    let endian = header.endian()?;
+    if header.e_phnum.get(endian) != 0 {
+        return Err("not a relocatable object".into());
+    }
+    let sections = header.section_headers(endian, input)?;
+    let strings = header.section_strings(endian, input, sections)?;
+    let is_debug = |index: u32| {
+        let name = sections.get(index as usize).and_then(|s| s.name(endian, strings).ok());
+        name.is_some_and(|name| name.starts_with(b".debug"))
+    };
+
+    // Sections keep their index and only their data changes, so symbols,
+    // relocations and groups referring to them stay valid. Stripped sections
+    // are left empty.
+    let mut output = input[..core::mem::size_of::<FileHeader32<Endianness>>()].to_vec();
+    let mut headers = Vec::with_capacity(sections.len());
+    for (index, section) in sections.iter().enumerate() {
+        let mut new = *section;
+        if index == 0 || section.sh_type(endian) == SHT_NOBITS {
+            headers.push(new);
+            continue;
         }
+
+        let flags = section.sh_flags(endian);
+        let relocates_debug =
+            matches!(section.sh_type(endian), SHT_REL | SHT_RELA) && is_debug(section.sh_info(endian));
+        let strip = cfg!(feature = "strip-debug") && (is_debug(index as u32) || relocates_debug);
+        let data = section.data(endian, input)?;
+        let data = if strip {
+            new.sh_flags.set(endian, flags & !SHF_COMPRESSED);
+            Cow::Borrowed(&[][..])
+        } else if flags & SHF_COMPRESSED != 0 {
+            let (compression, compressed) = object::pod::from_bytes::<CompressionHeader32<Endianness>>(data)
+                .map_err(|()| "truncated compression header")?;
+            let format = match compression.ch_type.get(endian) {
+                object::elf::ELFCOMPRESS_ZLIB => CompressionFormat::Zlib,
+                object::elf::ELFCOMPRESS_ZSTD => CompressionFormat::Zstandard,
+                _ => return Err("unknown section compression".into()),
+            };
+            new.sh_flags.set(endian, flags & !SHF_COMPRESSED);
+            new.sh_addralign.set(endian, compression.ch_addralign.get(endian));
+            CompressedData {
+                format,
+                data: compressed,
+                uncompressed_size: compression.ch_size.get(endian) as u64,
+            }
+            .decompress()?
+        } else {
+            Cow::Borrowed(data)
+        };
+
+        let align = new.sh_addralign.get(endian).max(1) as usize;
+        output.resize(output.len().next_multiple_of(align), 0);
+        new.sh_offset.set(endian, output.len() as u32);
+        new.sh_size.set(endian, data.len() as u32);
+        output.extend_from_slice(&data);
+        headers.push(new);
     }
 
-    // Make sure we link against the libraries
-    println!(
-        "cargo:rustc-link-search={}",
-        libmpsl_changed_path.parent().unwrap().display()
-    );
-    println!("cargo:rustc-link-lib=static=mpsl");
+    output.resize(output.len().next_multiple_of(4), 0);
+    let mut new_header = *header;
+    new_header.e_shoff.set(endian, output.len() as u32);
+    for section in &headers {
+        output.extend_from_slice(object::pod::bytes_of(section));
+    }
+    let header_bytes = object::pod::bytes_of(&new_header);
+    output[..header_bytes.len()].copy_from_slice(header_bytes);
+    Ok(output)
+}
+
+/// Write a GNU archive of `members` with the symbol index the linker needs.
+fn write_archive(members: &[(Vec<u8>, Vec<u8>)]) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    use object::{Object, ObjectSymbol};
+
+    const MAGIC: &[u8] = b"!<arch>\n";
+    const HEADER_SIZE: usize = 60;
+    let padded = |len: usize| len + len % 2;
+
+    // Names longer than 15 bytes go to the `//` member, referenced by offset.
+    let mut long_names = Vec::new();
+    let mut identifiers = Vec::new();
+    for (name, _) in members {
+        if name.len() < 16 {
+            identifiers.push([name.as_slice(), b"/"].concat());
+        } else {
+            identifiers.push(format!("/{}", long_names.len()).into_bytes());
+            long_names.extend_from_slice(name);
+            long_names.extend_from_slice(b"/\n");
+        }
+    }
+
+    let mut symbols = Vec::new();
+    for (index, (_, data)) in members.iter().enumerate() {
+        let Ok(file) = object::File::parse(data.as_slice()) else {
+            continue;
+        };
+        for symbol in file.symbols() {
+            if symbol.is_global() && !symbol.is_undefined() {
+                symbols.push((index, symbol.name_bytes()?.to_vec()));
+            }
+        }
+    }
+    let symbol_table_size = 4 + 4 * symbols.len() + symbols.iter().map(|(_, name)| name.len() + 1).sum::<usize>();
+
+    let mut offset = MAGIC.len() + HEADER_SIZE + padded(symbol_table_size);
+    if !long_names.is_empty() {
+        offset += HEADER_SIZE + padded(long_names.len());
+    }
+    let mut offsets = Vec::new();
+    for (_, data) in members {
+        offsets.push(offset as u32);
+        offset += HEADER_SIZE + padded(data.len());
+    }
+
+    let mut out = Vec::with_capacity(offset);
+    let append = |out: &mut Vec<u8>, identifier: &[u8], data: &[u8]| {
+        let header = format!(
+            "{:<16}{:<12}{:<6}{:<6}{:<8o}{:<10}`\n",
+            String::from_utf8_lossy(identifier),
+            0,
+            0,
+            0,
+            0o644,
+            data.len()
+        );
+        assert_eq!(header.len(), HEADER_SIZE);
+        out.extend_from_slice(header.as_bytes());
+        out.extend_from_slice(data);
+        if data.len() % 2 == 1 {
+            out.push(b'\n');
+        }
+    };
+
+    out.extend_from_slice(MAGIC);
+    let mut symbol_table = Vec::with_capacity(symbol_table_size);
+    symbol_table.extend_from_slice(&(symbols.len() as u32).to_be_bytes());
+    for (index, _) in &symbols {
+        symbol_table.extend_from_slice(&offsets[*index].to_be_bytes());
+    }
+    for (_, name) in &symbols {
+        symbol_table.extend_from_slice(name);
+        symbol_table.push(0);
+    }
+    append(&mut out, b"/", &symbol_table);
+    if !long_names.is_empty() {
+        append(&mut out, b"//", &long_names);
+    }
+    for ((_, data), identifier) in members.iter().zip(&identifiers) {
+        append(&mut out, identifier, data);
+    }
+    Ok(out)
 }
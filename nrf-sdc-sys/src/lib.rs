@@ -26,5 +26,14 @@ pub mod ctypes {
     }
 }
 
+// Generated for chips with PPI. The types and functions are the same on chips
+// with DPPI, which only gain the constants of `mpsl_dppi_protocol_api.h`, see
+// `nrf-sdc-gen --dppi`. With the `bindgen` feature they are generated by the
+// build script instead, for the chip of the target.
+#[cfg(not(feature = "bindgen"))]
 mod bindings;
+#[cfg(feature = "bindgen")]
+mod bindings {
+    include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
+}
 pub use bindings::*;
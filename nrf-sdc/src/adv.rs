@@ -0,0 +1,376 @@
+//! Extended advertising with multiple concurrent advertising sets.
+//!
+//! Requires [`sdc_run`](crate::sdc::sdc_run) to be running, and `adv_count` in the
+//! [`sdc::Config`](crate::sdc::Config) to cover the number of sets in use.
+use super::conn;
+use super::hci::{self, AddrKind, Event, LeEvent, Phy};
+use super::raw;
+use super::tx_power::TxPower;
+use super::util::OnDrop;
+use super::Error;
+use core::cell::RefCell;
+use core::future::poll_fn;
+use core::task::Poll;
+use embassy_sync::blocking_mutex::CriticalSectionMutex;
+use embassy_sync::waitqueue::AtomicWaker;
+
+/// Maximum number of advertising sets handled by the [`Advertiser`].
+pub const MAX_ADV_SETS: usize = 8;
+
+/// Maximum data carried by a single LE Set Extended Advertising Data command.
+const MAX_FRAGMENT_LEN: usize = 251;
+const MAX_LEGACY_DATA_LEN: usize = 31;
+
+pub(crate) const OP_INTERMEDIATE: u8 = 0x00;
+pub(crate) const OP_FIRST: u8 = 0x01;
+pub(crate) const OP_LAST: u8 = 0x02;
+pub(crate) const OP_COMPLETE: u8 = 0x03;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum AdvKind {
+    /// Legacy connectable and scannable undirected advertising (ADV_IND).
+    LegacyConnectableScannable,
+    /// Legacy scannable undirected advertising (ADV_SCAN_IND).
+    LegacyScannable,
+    /// Legacy non-connectable advertising (ADV_NONCONN_IND).
+    LegacyNonConnectable,
+    ExtConnectable,
+    ExtScannable,
+    ExtNonConnectable,
+}
+
+impl AdvKind {
+    fn properties(self) -> u16 {
+        match self {
+            Self::LegacyConnectableScannable => 0x13,
+            Self::LegacyScannable => 0x12,
+            Self::LegacyNonConnectable => 0x10,
+            Self::ExtConnectable => 0x01,
+            Self::ExtScannable => 0x02,
+            Self::ExtNonConnectable => 0x00,
+        }
+    }
+
+    fn is_legacy(self) -> bool {
+        self.properties() & 0x10 != 0
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum AdvFilterPolicy {
+    Unfiltered = 0,
+    /// Only answer scan requests from devices in the filter accept list.
+    FilterScan = 1,
+    /// Only accept connections from devices in the filter accept list.
+    FilterConn = 2,
+    FilterScanAndConn = 3,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AdvParams {
+    pub kind: AdvKind,
+    /// Include the TX power in the extended advertising header.
+    pub include_tx_power: bool,
+    /// Minimum advertising interval in units of 0.625 ms, from 0x20 to 0xFFFFFF.
+    pub interval_min: u32,
+    /// Maximum advertising interval in units of 0.625 ms, from 0x20 to 0xFFFFFF.
+    pub interval_max: u32,
+    /// Bit mask of the primary advertising channels 37, 38 and 39.
+    pub channel_map: u8,
+    pub own_addr_kind: AddrKind,
+    pub filter_policy: AdvFilterPolicy,
+    /// Requested TX power, or `None` to let the controller choose.
+    pub tx_power: Option<TxPower>,
+    /// PHY of the primary advertising channels, either 1M or Coded.
+    pub primary_phy: Phy,
+    pub secondary_phy: Phy,
+    /// Advertising SID carried in the extended advertising header.
+    pub sid: u8,
+}
+
+impl Default for AdvParams {
+    fn default() -> Self {
+        Self {
+            kind: AdvKind::LegacyConnectableScannable,
+            include_tx_power: false,
+            interval_min: 160,
+            interval_max: 160,
+            channel_map: 0x07,
+            own_addr_kind: AddrKind::Public,
+            filter_policy: AdvFilterPolicy::Unfiltered,
+            tx_power: None,
+            primary_phy: Phy::Le1M,
+            secondary_phy: Phy::Le1M,
+            sid: 0,
+        }
+    }
+}
+
+/// Why an advertising set stopped advertising.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Terminated {
+    /// A connection was established with the set.
+    Connected { conn_handle: u16, completed_events: u8 },
+    /// The duration passed to [`AdvSet::advertise`] elapsed.
+    Timeout,
+    /// The maximum number of extended advertising events was reached.
+    MaxEvents { completed_events: u8 },
+}
+
+struct SetState {
+    allocated: bool,
+    terminated: Option<Result<Terminated, Error>>,
+}
+
+impl SetState {
+    const fn new() -> Self {
+        Self {
+            allocated: false,
+            terminated: None,
+        }
+    }
+}
+
+const NEW_SET: SetState = SetState::new();
+static SETS: CriticalSectionMutex<RefCell<[SetState; MAX_ADV_SETS]>> =
+    CriticalSectionMutex::new(RefCell::new([NEW_SET; MAX_ADV_SETS]));
+
+#[allow(clippy::declare_interior_mutable_const)]
+const NEW_WAKER: AtomicWaker = AtomicWaker::new();
+static WAKERS: [AtomicWaker; MAX_ADV_SETS] = [NEW_WAKER; MAX_ADV_SETS];
+
+pub(crate) fn on_event(event: &Event) {
+    if let Event::Le(LeEvent::AdvSetTerminated(t)) = event {
+        let index = t.adv_handle as usize;
+        if index >= MAX_ADV_SETS {
+            return;
+        }
+        let result = match t.status {
+            hci::STATUS_SUCCESS => Ok(Terminated::Connected {
+                conn_handle: t.conn_handle,
+                completed_events: t.num_completed_events,
+            }),
+            hci::STATUS_ADV_TIMEOUT => Ok(Terminated::Timeout),
+            hci::STATUS_LIMIT_REACHED => Ok(Terminated::MaxEvents {
+                completed_events: t.num_completed_events,
+            }),
+            code => Err(Error::Hci(code)),
+        };
+        SETS.lock(|sets| sets.borrow_mut()[index].terminated = Some(result));
+        WAKERS[index].wake();
+    }
+}
+
+/// Manages the advertising sets of the controller.
+pub struct Advertiser {
+    num_sets: usize,
+    max_data_len: usize,
+}
+
+impl Advertiser {
+    pub fn new() -> Result<Self, Error> {
+        let mut ret = raw::sdc_hci_cmd_le_read_number_of_supported_adv_sets_return_t {
+            num_supported_adv_sets: 0,
+        };
+        hci::status(unsafe { raw::sdc_hci_cmd_le_read_number_of_supported_adv_sets(&mut ret) })?;
+        let num_sets = core::cmp::min(ret.num_supported_adv_sets as usize, MAX_ADV_SETS);
+
+        let mut ret = raw::sdc_hci_cmd_le_read_max_adv_data_length_return_t { max_adv_data_length: 0 };
+        hci::status(unsafe { raw::sdc_hci_cmd_le_read_max_adv_data_length(&mut ret) })?;
+        let max_data_len = ret.max_adv_data_length as usize;
+
+        hci::enable_le_events(&[hci::SUBEVT_ADV_SET_TERMINATED])?;
+        conn::enable_events()?;
+        info!("[adv] {} sets, max data length {}", num_sets, max_data_len);
+        Ok(Self { num_sets, max_data_len })
+    }
+
+    /// Maximum advertising or scan response data length of a set.
+    pub fn max_data_len(&self) -> usize {
+        self.max_data_len
+    }
+
+    /// Create a new advertising set. The set is removed when dropped.
+    pub fn create_set(&self, params: &AdvParams) -> Result<AdvSet<'_>, Error> {
+        let handle = SETS.lock(|sets| {
+            let mut sets = sets.borrow_mut();
+            let index = sets[..self.num_sets].iter().position(|s| !s.allocated)?;
+            sets[index] = SetState {
+                allocated: true,
+                terminated: None,
+            };
+            Some(index as u8)
+        });
+        let Some(handle) = handle else {
+            return Err(Error::NoMem);
+        };
+
+        let mut set = AdvSet {
+            handle,
+            legacy: params.kind.is_legacy(),
+            selected_tx_power: 0,
+            advertiser: self,
+        };
+        set.set_params(params)?;
+        Ok(set)
+    }
+
+    /// Remove all advertising sets from the controller.
+    pub fn clear(&mut self) -> Result<(), Error> {
+        hci::status(unsafe { raw::sdc_hci_cmd_le_clear_adv_sets() })
+    }
+}
+
+/// An advertising set created by [`Advertiser::create_set`].
+pub struct AdvSet<'a> {
+    handle: u8,
+    legacy: bool,
+    selected_tx_power: i8,
+    advertiser: &'a Advertiser,
+}
+
+impl<'a> AdvSet<'a> {
+    pub fn handle(&self) -> u8 {
+        self.handle
+    }
+
+    /// TX power selected by the controller for this set, in dBm.
+    pub fn selected_tx_power(&self) -> i8 {
+        self.selected_tx_power
+    }
+
+    /// Update the parameters of the set. The set must not be advertising.
+    pub fn set_params(&mut self, params: &AdvParams) -> Result<(), Error> {
+        let mut properties = params.kind.properties();
+        if params.include_tx_power {
+            properties |= 0x40;
+        }
+        let cmd = raw::sdc_hci_cmd_le_set_ext_adv_params_t {
+            adv_handle: self.handle,
+            adv_event_properties: properties,
+            primary_adv_interval_min: interval(params.interval_min)?,
+            primary_adv_interval_max: interval(params.interval_max)?,
+            primary_adv_channel_map: params.channel_map,
+            own_address_type: params.own_addr_kind as u8,
+            peer_address_type: 0,
+            peer_address: [0; 6],
+            adv_filter_policy: params.filter_policy as u8,
+            adv_tx_power: params.tx_power.map_or(0x7F, TxPower::dbm),
+            primary_adv_phy: params.primary_phy as u8,
+            secondary_adv_max_skip: 0,
+            secondary_adv_phy: params.secondary_phy as u8,
+            adv_sid: params.sid,
+            scan_request_notification_enable: 0,
+        };
+        let mut ret = raw::sdc_hci_cmd_le_set_ext_adv_params_return_t { selected_tx_power: 0 };
+        hci::status(unsafe { raw::sdc_hci_cmd_le_set_ext_adv_params(&cmd, &mut ret) })?;
+        self.legacy = params.kind.is_legacy();
+        self.selected_tx_power = ret.selected_tx_power;
+        Ok(())
+    }
+
+    /// Set the advertising data, split into as many commands as needed.
+    pub fn set_data(&mut self, data: &[u8]) -> Result<(), Error> {
+        self.write_data(data, |buf| unsafe {
+            raw::sdc_hci_cmd_le_set_ext_adv_data(buf.as_ptr() as *const _)
+        })
+    }
+
+    /// Set the scan response data, split into as many commands as needed.
+    pub fn set_scan_response_data(&mut self, data: &[u8]) -> Result<(), Error> {
+        self.write_data(data, |buf| unsafe {
+            raw::sdc_hci_cmd_le_set_ext_scan_response_data(buf.as_ptr() as *const _)
+        })
+    }
+
+    // Both data commands share the same layout: handle, operation, fragment
+    // preference, length and the data itself.
+    fn write_data(&mut self, data: &[u8], mut cmd: impl FnMut(&[u8]) -> u8) -> Result<(), Error> {
+        let max_len = if self.legacy {
+            MAX_LEGACY_DATA_LEN
+        } else {
+            self.advertiser.max_data_len
+        };
+        if data.len() > max_len {
+            return Err(Error::InvalidArg);
+        }
+
+        let mut buf = [0; 4 + MAX_FRAGMENT_LEN];
+        let count = core::cmp::max(1, data.len().div_ceil(MAX_FRAGMENT_LEN));
+        for i in 0..count {
+            let fragment = &data[i * MAX_FRAGMENT_LEN..core::cmp::min(data.len(), (i + 1) * MAX_FRAGMENT_LEN)];
+            buf[0] = self.handle;
+            buf[1] = match i {
+                _ if count == 1 => OP_COMPLETE,
+                0 => OP_FIRST,
+                _ if i == count - 1 => OP_LAST,
+                _ => OP_INTERMEDIATE,
+            };
+            // Let the controller fragment the data as it sees fit.
+            buf[2] = 0;
+            buf[3] = fragment.len() as u8;
+            buf[4..4 + fragment.len()].copy_from_slice(fragment);
+            hci::status(cmd(&buf))?;
+        }
+        Ok(())
+    }
+
+    /// Advertise until the set terminates.
+    ///
+    /// `duration` is in units of 10 ms and `max_events` limits the number of extended
+    /// advertising events; zero disables the respective limit. Advertising is stopped
+    /// if the returned future is dropped before completion.
+    pub async fn advertise(&mut self, duration: u16, max_events: u8) -> Result<Terminated, Error> {
+        let index = self.handle as usize;
+        SETS.lock(|sets| sets.borrow_mut()[index].terminated = None);
+        set_enable(self.handle, true, duration, max_events)?;
+
+        let handle = self.handle;
+        let on_drop = OnDrop::new(|| {
+            let _ = set_enable(handle, false, 0, 0);
+        });
+        let result = poll_fn(|cx| {
+            WAKERS[index].register(cx.waker());
+            match SETS.lock(|sets| sets.borrow_mut()[index].terminated.take()) {
+                Some(result) => Poll::Ready(result),
+                None => Poll::Pending,
+            }
+        })
+        .await;
+        on_drop.defuse();
+        result
+    }
+}
+
+impl<'a> Drop for AdvSet<'a> {
+    fn drop(&mut self) {
+        let _ = set_enable(self.handle, false, 0, 0);
+        let ret = unsafe {
+            raw::sdc_hci_cmd_le_remove_adv_set(&raw::sdc_hci_cmd_le_remove_adv_set_t {
+                adv_handle: self.handle,
+            })
+        };
+        if let Err(e) = hci::status(ret) {
+            warn!("[adv] failed to remove set {}: {:?}", self.handle, e);
+        }
+        SETS.lock(|sets| sets.borrow_mut()[self.handle as usize] = SetState::new());
+    }
+}
+
+fn set_enable(handle: u8, enable: bool, duration: u16, max_events: u8) -> Result<(), Error> {
+    let duration = duration.to_le_bytes();
+    let buf = [enable as u8, 1, handle, duration[0], duration[1], max_events];
+    hci::status(unsafe { raw::sdc_hci_cmd_le_set_ext_adv_enable(buf.as_ptr() as *const _) })
+}
+
+fn interval(value: u32) -> Result<[u8; 3], Error> {
+    if !(0x20..=0xFF_FFFF).contains(&value) {
+        return Err(Error::InvalidArg);
+    }
+    let bytes = value.to_le_bytes();
+    Ok([bytes[0], bytes[1], bytes[2]])
+}
@@ -0,0 +1,555 @@
+//! Encoding and decoding of the AD structures carried in advertising and scan
+//! response data (Core Supplement, Part A).
+//!
+//! This module does not touch the controller, so it can be used with any host.
+use super::Error;
+
+/// Maximum data length of legacy advertising PDUs.
+pub const LEGACY_MAX_LEN: usize = 31;
+/// Maximum data length of an extended advertising set.
+pub const EXTENDED_MAX_LEN: usize = 1650;
+
+pub const LE_LIMITED_DISCOVERABLE: u8 = 0x01;
+pub const LE_GENERAL_DISCOVERABLE: u8 = 0x02;
+pub const BR_EDR_NOT_SUPPORTED: u8 = 0x04;
+
+pub mod ad_type {
+    pub const FLAGS: u8 = 0x01;
+    pub const INCOMPLETE_UUIDS16: u8 = 0x02;
+    pub const COMPLETE_UUIDS16: u8 = 0x03;
+    pub const INCOMPLETE_UUIDS32: u8 = 0x04;
+    pub const COMPLETE_UUIDS32: u8 = 0x05;
+    pub const INCOMPLETE_UUIDS128: u8 = 0x06;
+    pub const COMPLETE_UUIDS128: u8 = 0x07;
+    pub const SHORTENED_LOCAL_NAME: u8 = 0x08;
+    pub const COMPLETE_LOCAL_NAME: u8 = 0x09;
+    pub const TX_POWER_LEVEL: u8 = 0x0A;
+    pub const SERVICE_DATA16: u8 = 0x16;
+    pub const APPEARANCE: u8 = 0x19;
+    pub const SERVICE_DATA32: u8 = 0x20;
+    pub const SERVICE_DATA128: u8 = 0x21;
+    pub const URI: u8 = 0x24;
+    pub const LE_SUPPORTED_FEATURES: u8 = 0x27;
+    pub const MANUFACTURER_DATA: u8 = 0xFF;
+}
+
+// URI scheme name string codes from the Bluetooth Assigned Numbers.
+const URI_NO_SCHEME: char = '\u{01}';
+const URI_SCHEMES: [(&str, char); 2] = [("http:", '\u{16}'), ("https:", '\u{17}')];
+
+/// Builds advertising data into a caller provided buffer.
+pub struct AdvDataBuilder<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl<'a> AdvDataBuilder<'a> {
+    /// Build data for legacy advertising, limited to [`LEGACY_MAX_LEN`] bytes.
+    pub fn legacy(buf: &'a mut [u8]) -> Self {
+        Self::with_limit(buf, LEGACY_MAX_LEN)
+    }
+
+    /// Build data for extended advertising, limited to [`EXTENDED_MAX_LEN`] bytes.
+    pub fn extended(buf: &'a mut [u8]) -> Self {
+        Self::with_limit(buf, EXTENDED_MAX_LEN)
+    }
+
+    fn with_limit(buf: &'a mut [u8], limit: usize) -> Self {
+        let len = core::cmp::min(buf.len(), limit);
+        Self {
+            buf: &mut buf[..len],
+            len: 0,
+        }
+    }
+
+    /// Space left for the data of another AD structure.
+    pub fn remaining(&self) -> usize {
+        (self.buf.len() - self.len).saturating_sub(2)
+    }
+
+    pub fn flags(&mut self, flags: u8) -> Result<&mut Self, Error> {
+        self.raw(ad_type::FLAGS, &[flags])
+    }
+
+    pub fn uuids16(&mut self, complete: bool, uuids: &[u16]) -> Result<&mut Self, Error> {
+        let ad = if complete {
+            ad_type::COMPLETE_UUIDS16
+        } else {
+            ad_type::INCOMPLETE_UUIDS16
+        };
+        let field = self.field(ad, uuids.len() * 2)?;
+        for (chunk, uuid) in field.chunks_exact_mut(2).zip(uuids) {
+            chunk.copy_from_slice(&uuid.to_le_bytes());
+        }
+        Ok(self)
+    }
+
+    pub fn uuids32(&mut self, complete: bool, uuids: &[u32]) -> Result<&mut Self, Error> {
+        let ad = if complete {
+            ad_type::COMPLETE_UUIDS32
+        } else {
+            ad_type::INCOMPLETE_UUIDS32
+        };
+        let field = self.field(ad, uuids.len() * 4)?;
+        for (chunk, uuid) in field.chunks_exact_mut(4).zip(uuids) {
+            chunk.copy_from_slice(&uuid.to_le_bytes());
+        }
+        Ok(self)
+    }
+
+    /// 128-bit UUIDs are given in little endian byte order.
+    pub fn uuids128(&mut self, complete: bool, uuids: &[[u8; 16]]) -> Result<&mut Self, Error> {
+        let ad = if complete {
+            ad_type::COMPLETE_UUIDS128
+        } else {
+            ad_type::INCOMPLETE_UUIDS128
+        };
+        let field = self.field(ad, uuids.len() * 16)?;
+        for (chunk, uuid) in field.chunks_exact_mut(16).zip(uuids) {
+            chunk.copy_from_slice(uuid);
+        }
+        Ok(self)
+    }
+
+    /// Add the complete local name, or a shortened name truncated to the
+    /// remaining space if the complete name does not fit.
+    pub fn local_name(&mut self, name: &str) -> Result<&mut Self, Error> {
+        if name.len() <= self.remaining() {
+            return self.raw(ad_type::COMPLETE_LOCAL_NAME, name.as_bytes());
+        }
+        let mut end = self.remaining();
+        while !name.is_char_boundary(end) {
+            end -= 1;
+        }
+        if end == 0 {
+            return Err(Error::NoMem);
+        }
+        self.raw(ad_type::SHORTENED_LOCAL_NAME, &name.as_bytes()[..end])
+    }
+
+    pub fn tx_power(&mut self, dbm: i8) -> Result<&mut Self, Error> {
+        self.raw(ad_type::TX_POWER_LEVEL, &[dbm as u8])
+    }
+
+    pub fn appearance(&mut self, appearance: u16) -> Result<&mut Self, Error> {
+        self.raw(ad_type::APPEARANCE, &appearance.to_le_bytes())
+    }
+
+    pub fn service_data16(&mut self, uuid: u16, data: &[u8]) -> Result<&mut Self, Error> {
+        self.prefixed(ad_type::SERVICE_DATA16, &uuid.to_le_bytes(), data)
+    }
+
+    pub fn service_data32(&mut self, uuid: u32, data: &[u8]) -> Result<&mut Self, Error> {
+        self.prefixed(ad_type::SERVICE_DATA32, &uuid.to_le_bytes(), data)
+    }
+
+    pub fn service_data128(&mut self, uuid: &[u8; 16], data: &[u8]) -> Result<&mut Self, Error> {
+        self.prefixed(ad_type::SERVICE_DATA128, uuid, data)
+    }
+
+    pub fn manufacturer_data(&mut self, company_id: u16, data: &[u8]) -> Result<&mut Self, Error> {
+        self.prefixed(ad_type::MANUFACTURER_DATA, &company_id.to_le_bytes(), data)
+    }
+
+    /// Add a URI. The `http:` and `https:` schemes are replaced by their
+    /// scheme name string code.
+    pub fn uri(&mut self, uri: &str) -> Result<&mut Self, Error> {
+        let (scheme, rest) = URI_SCHEMES
+            .iter()
+            .find_map(|(prefix, code)| Some((*code, uri.strip_prefix(prefix)?)))
+            .unwrap_or((URI_NO_SCHEME, uri));
+        let mut code = [0; 4];
+        self.prefixed(ad_type::URI, scheme.encode_utf8(&mut code).as_bytes(), rest.as_bytes())
+    }
+
+    /// LE supported features, with trailing zero octets omitted.
+    pub fn le_supported_features(&mut self, features: &[u8]) -> Result<&mut Self, Error> {
+        let len = features.iter().rposition(|b| *b != 0).map_or(0, |i| i + 1);
+        self.raw(ad_type::LE_SUPPORTED_FEATURES, &features[..len])
+    }
+
+    /// Add a decoded AD structure, for instance to forward a received report.
+    pub fn push(&mut self, ad: &AdStructure) -> Result<&mut Self, Error> {
+        match *ad {
+            AdStructure::Flags(flags) => self.flags(flags),
+            AdStructure::Uuids16 { complete, uuids } => self.raw(uuid_type(complete, 2), uuids.0),
+            AdStructure::Uuids32 { complete, uuids } => self.raw(uuid_type(complete, 4), uuids.0),
+            AdStructure::Uuids128 { complete, uuids } => self.raw(uuid_type(complete, 16), uuids.0),
+            AdStructure::LocalName { complete: true, name } => self.raw(ad_type::COMPLETE_LOCAL_NAME, name.as_bytes()),
+            AdStructure::LocalName { complete: false, name } => {
+                self.raw(ad_type::SHORTENED_LOCAL_NAME, name.as_bytes())
+            }
+            AdStructure::TxPower(dbm) => self.tx_power(dbm),
+            AdStructure::Appearance(appearance) => self.appearance(appearance),
+            AdStructure::ServiceData16 { uuid, data } => self.service_data16(uuid, data),
+            AdStructure::ServiceData32 { uuid, data } => self.service_data32(uuid, data),
+            AdStructure::ServiceData128 { ref uuid, data } => self.service_data128(uuid, data),
+            AdStructure::Uri(uri) => self.raw(ad_type::URI, uri.as_bytes()),
+            AdStructure::LeSupportedFeatures(features) => self.raw(ad_type::LE_SUPPORTED_FEATURES, features),
+            AdStructure::ManufacturerData { company_id, data } => self.manufacturer_data(company_id, data),
+            AdStructure::Unknown { ad_type, data } => self.raw(ad_type, data),
+        }
+    }
+
+    /// Add an AD structure of any type.
+    pub fn raw(&mut self, ad_type: u8, data: &[u8]) -> Result<&mut Self, Error> {
+        self.field(ad_type, data.len())?.copy_from_slice(data);
+        Ok(self)
+    }
+
+    /// The encoded data.
+    pub fn data(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+
+    fn prefixed(&mut self, ad_type: u8, prefix: &[u8], data: &[u8]) -> Result<&mut Self, Error> {
+        let field = self.field(ad_type, prefix.len() + data.len())?;
+        field[..prefix.len()].copy_from_slice(prefix);
+        field[prefix.len()..].copy_from_slice(data);
+        Ok(self)
+    }
+
+    // Reserve an AD structure with `len` data bytes and return the data part.
+    fn field(&mut self, ad_type: u8, len: usize) -> Result<&mut [u8], Error> {
+        // `remaining` saturates, so check the length and type bytes as well.
+        if len > u8::MAX as usize - 1 || len + 2 > self.buf.len() - self.len {
+            return Err(Error::NoMem);
+        }
+        let start = self.len;
+        self.buf[start] = len as u8 + 1;
+        self.buf[start + 1] = ad_type;
+        self.len += len + 2;
+        Ok(&mut self.buf[start + 2..self.len])
+    }
+}
+
+fn uuid_type(complete: bool, size: usize) -> u8 {
+    let incomplete = match size {
+        2 => ad_type::INCOMPLETE_UUIDS16,
+        4 => ad_type::INCOMPLETE_UUIDS32,
+        _ => ad_type::INCOMPLETE_UUIDS128,
+    };
+    incomplete + complete as u8
+}
+
+/// A list of UUIDs of `N` bytes each, in little endian byte order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct UuidList<'a, const N: usize>(&'a [u8]);
+
+impl<'a, const N: usize> UuidList<'a, N> {
+    pub fn iter(&self) -> impl Iterator<Item = [u8; N]> + 'a {
+        self.0.chunks_exact(N).map(|c| c.try_into().unwrap())
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len() / N
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+/// A decoded AD structure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum AdStructure<'a> {
+    Flags(u8),
+    Uuids16 {
+        complete: bool,
+        uuids: UuidList<'a, 2>,
+    },
+    Uuids32 {
+        complete: bool,
+        uuids: UuidList<'a, 4>,
+    },
+    Uuids128 {
+        complete: bool,
+        uuids: UuidList<'a, 16>,
+    },
+    LocalName {
+        complete: bool,
+        name: &'a str,
+    },
+    TxPower(i8),
+    Appearance(u16),
+    ServiceData16 {
+        uuid: u16,
+        data: &'a [u8],
+    },
+    ServiceData32 {
+        uuid: u32,
+        data: &'a [u8],
+    },
+    ServiceData128 {
+        uuid: [u8; 16],
+        data: &'a [u8],
+    },
+    /// URI starting with its scheme name string code.
+    Uri(&'a str),
+    LeSupportedFeatures(&'a [u8]),
+    ManufacturerData {
+        company_id: u16,
+        data: &'a [u8],
+    },
+    Unknown {
+        ad_type: u8,
+        data: &'a [u8],
+    },
+}
+
+impl<'a> AdStructure<'a> {
+    fn decode(ad: u8, data: &'a [u8]) -> Result<Self, Error> {
+        let ad = match ad {
+            ad_type::FLAGS => Self::Flags(*data.first().ok_or(Error::InvalidArg)?),
+            ad_type::INCOMPLETE_UUIDS16 | ad_type::COMPLETE_UUIDS16 => Self::Uuids16 {
+                complete: ad == ad_type::COMPLETE_UUIDS16,
+                uuids: uuid_list(data)?,
+            },
+            ad_type::INCOMPLETE_UUIDS32 | ad_type::COMPLETE_UUIDS32 => Self::Uuids32 {
+                complete: ad == ad_type::COMPLETE_UUIDS32,
+                uuids: uuid_list(data)?,
+            },
+            ad_type::INCOMPLETE_UUIDS128 | ad_type::COMPLETE_UUIDS128 => Self::Uuids128 {
+                complete: ad == ad_type::COMPLETE_UUIDS128,
+                uuids: uuid_list(data)?,
+            },
+            ad_type::SHORTENED_LOCAL_NAME | ad_type::COMPLETE_LOCAL_NAME => Self::LocalName {
+                complete: ad == ad_type::COMPLETE_LOCAL_NAME,
+                name: core::str::from_utf8(data).map_err(|_| Error::InvalidArg)?,
+            },
+            ad_type::TX_POWER_LEVEL => Self::TxPower(*data.first().ok_or(Error::InvalidArg)? as i8),
+            ad_type::APPEARANCE => Self::Appearance(u16::from_le_bytes(prefix(data)?.0)),
+            ad_type::SERVICE_DATA16 => {
+                let (uuid, data) = prefix(data)?;
+                Self::ServiceData16 {
+                    uuid: u16::from_le_bytes(uuid),
+                    data,
+                }
+            }
+            ad_type::SERVICE_DATA32 => {
+                let (uuid, data) = prefix(data)?;
+                Self::ServiceData32 {
+                    uuid: u32::from_le_bytes(uuid),
+                    data,
+                }
+            }
+            ad_type::SERVICE_DATA128 => {
+                let (uuid, data) = prefix(data)?;
+                Self::ServiceData128 { uuid, data }
+            }
+            ad_type::URI => Self::Uri(core::str::from_utf8(data).map_err(|_| Error::InvalidArg)?),
+            ad_type::LE_SUPPORTED_FEATURES => Self::LeSupportedFeatures(data),
+            ad_type::MANUFACTURER_DATA => {
+                let (company_id, data) = prefix(data)?;
+                Self::ManufacturerData {
+                    company_id: u16::from_le_bytes(company_id),
+                    data,
+                }
+            }
+            ad_type => Self::Unknown { ad_type, data },
+        };
+        Ok(ad)
+    }
+}
+
+fn uuid_list<const N: usize>(data: &[u8]) -> Result<UuidList<'_, N>, Error> {
+    match data.len() % N {
+        0 => Ok(UuidList(data)),
+        _ => Err(Error::InvalidArg),
+    }
+}
+
+fn prefix<const N: usize>(data: &[u8]) -> Result<([u8; N], &[u8]), Error> {
+    if data.len() < N {
+        return Err(Error::InvalidArg);
+    }
+    let (head, tail) = data.split_at(N);
+    Ok((head.try_into().unwrap(), tail))
+}
+
+/// Iterate over the AD structures in advertising or scan response data.
+///
+/// Iteration stops at the first zero length octet, which marks the start of
+/// the padding. Malformed data yields an error and ends the iteration.
+pub fn parse(data: &[u8]) -> AdStructures<'_> {
+    AdStructures { data }
+}
+
+pub struct AdStructures<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Iterator for AdStructures<'a> {
+    type Item = Result<AdStructure<'a>, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (&len, rest) = self.data.split_first()?;
+        let len = len as usize;
+        if len == 0 {
+            self.data = &[];
+            return None;
+        }
+        if rest.len() < len {
+            self.data = &[];
+            return Some(Err(Error::InvalidArg));
+        }
+        self.data = &rest[len..];
+        Some(AdStructure::decode(rest[0], &rest[1..len]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_legacy() {
+        let mut buf = [0; LEGACY_MAX_LEN];
+        let mut builder = AdvDataBuilder::legacy(&mut buf);
+        builder
+            .flags(LE_GENERAL_DISCOVERABLE | BR_EDR_NOT_SUPPORTED)
+            .unwrap()
+            .uuids16(true, &[0x1809])
+            .unwrap()
+            .local_name("nrf-sdc")
+            .unwrap();
+        assert_eq!(
+            builder.data(),
+            &[2, 0x01, 0x06, 3, 0x03, 0x09, 0x18, 8, 0x09, b'n', b'r', b'f', b'-', b's', b'd', b'c']
+        );
+    }
+
+    #[test]
+    fn legacy_limit() {
+        let mut buf = [0; EXTENDED_MAX_LEN];
+        let mut builder = AdvDataBuilder::legacy(&mut buf);
+        assert!(builder.manufacturer_data(0xFFFF, &[0; 28]).is_err());
+        builder.manufacturer_data(0xFFFF, &[0; 27]).unwrap();
+        assert_eq!(builder.data().len(), LEGACY_MAX_LEN);
+        assert_eq!(builder.remaining(), 0);
+    }
+
+    #[test]
+    fn empty_field_when_full() {
+        let mut buf = [0; LEGACY_MAX_LEN];
+        let mut builder = AdvDataBuilder::legacy(&mut buf);
+        builder.manufacturer_data(0xFFFF, &[0; 26]).unwrap();
+        assert_eq!(builder.data().len(), LEGACY_MAX_LEN - 1);
+        assert!(builder.local_name("").is_err());
+        assert!(builder.uuids16(true, &[]).is_err());
+        assert!(builder.raw(0xFF, &[]).is_err());
+        assert_eq!(builder.data().len(), LEGACY_MAX_LEN - 1);
+    }
+
+    #[test]
+    fn extended_limit() {
+        let mut buf = [0; 2048];
+        let mut builder = AdvDataBuilder::extended(&mut buf);
+        assert!(builder.raw(0xFF, &[0; 255]).is_err());
+        for _ in 0..6 {
+            builder.raw(0xFF, &[0; 254]).unwrap();
+        }
+        assert_eq!(builder.remaining(), EXTENDED_MAX_LEN - 6 * 256 - 2);
+        assert!(builder.raw(0xFF, &[0; 254]).is_err());
+    }
+
+    #[test]
+    fn shortened_name() {
+        let mut buf = [0; LEGACY_MAX_LEN];
+        let mut builder = AdvDataBuilder::legacy(&mut buf);
+        builder.manufacturer_data(0x0059, &[0; 20]).unwrap();
+        builder.local_name("a very long name").unwrap();
+        assert_eq!(
+            parse(builder.data()).nth(1).unwrap().unwrap(),
+            AdStructure::LocalName {
+                complete: false,
+                name: "a ver"
+            }
+        );
+    }
+
+    #[test]
+    fn uri_scheme() {
+        let mut buf = [0; LEGACY_MAX_LEN];
+        let mut builder = AdvDataBuilder::legacy(&mut buf);
+        builder.uri("https://example.com").unwrap();
+        assert_eq!(&builder.data()[..4], &[15, 0x24, 0x17, b'/']);
+    }
+
+    #[test]
+    fn roundtrip() {
+        let mut buf = [0; EXTENDED_MAX_LEN];
+        let mut builder = AdvDataBuilder::extended(&mut buf);
+        builder
+            .flags(LE_GENERAL_DISCOVERABLE)
+            .unwrap()
+            .uuids16(false, &[0x180F, 0x1809])
+            .unwrap()
+            .uuids32(true, &[0x12345678])
+            .unwrap()
+            .uuids128(true, &[[0xAA; 16]])
+            .unwrap()
+            .tx_power(-4)
+            .unwrap()
+            .appearance(0x03C1)
+            .unwrap()
+            .service_data16(0x180F, &[99])
+            .unwrap()
+            .le_supported_features(&[0x01, 0x00, 0x00])
+            .unwrap()
+            .manufacturer_data(0x0059, &[1, 2, 3])
+            .unwrap();
+
+        let ads: [AdStructure; 9] = core::array::from_fn({
+            let mut iter = parse(builder.data());
+            move |_| iter.next().unwrap().unwrap()
+        });
+        assert_eq!(ads[0], AdStructure::Flags(LE_GENERAL_DISCOVERABLE));
+        match ads[1] {
+            AdStructure::Uuids16 { complete: false, uuids } => {
+                assert_eq!(uuids.len(), 2);
+                assert_eq!(uuids.iter().map(u16::from_le_bytes).last(), Some(0x1809));
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+        assert_eq!(ads[4], AdStructure::TxPower(-4));
+        assert_eq!(ads[5], AdStructure::Appearance(0x03C1));
+        assert_eq!(
+            ads[6],
+            AdStructure::ServiceData16 {
+                uuid: 0x180F,
+                data: &[99]
+            }
+        );
+        assert_eq!(ads[7], AdStructure::LeSupportedFeatures(&[0x01]));
+        assert_eq!(
+            ads[8],
+            AdStructure::ManufacturerData {
+                company_id: 0x0059,
+                data: &[1, 2, 3]
+            }
+        );
+
+        let mut copy = [0; EXTENDED_MAX_LEN];
+        let mut rebuilt = AdvDataBuilder::extended(&mut copy);
+        for ad in ads.iter() {
+            rebuilt.push(ad).unwrap();
+        }
+        assert_eq!(rebuilt.data(), builder.data());
+    }
+
+    #[test]
+    fn parse_padding_and_errors() {
+        let mut ads = parse(&[2, 0x01, 0x06, 0, 0, 0]);
+        assert_eq!(ads.next().unwrap().unwrap(), AdStructure::Flags(0x06));
+        assert!(ads.next().is_none());
+
+        let mut ads = parse(&[5, 0x09, b'a']);
+        assert!(ads.next().unwrap().is_err());
+        assert!(ads.next().is_none());
+
+        assert!(parse(&[4, 0x03, 0x09, 0x18, 0x0F]).next().unwrap().is_err());
+    }
+}
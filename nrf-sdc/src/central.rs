@@ -0,0 +1,308 @@
+//! Establishing connections in the central role.
+//!
+//! Requires [`sdc_run`](crate::sdc::sdc_run) to be running.
+use super::conn::{self, Connection};
+use super::hci::{self, AddrKind, Address, Event, LeEvent};
+use super::raw;
+use super::util::OnDrop;
+use super::Error;
+use core::cell::Cell;
+use core::future::{pending, poll_fn, Future};
+use core::pin::pin;
+use core::sync::atomic::{AtomicBool, Ordering};
+use core::task::Poll;
+use embassy_sync::blocking_mutex::CriticalSectionMutex;
+use embassy_sync::waitqueue::AtomicWaker;
+
+/// Which device to connect to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Target {
+    Peer(Address),
+    /// Connect to the first advertiser found in the filter accept list.
+    AcceptList,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ConnectParams {
+    pub own_addr_kind: AddrKind,
+    /// Scan interval in units of 0.625 ms.
+    pub scan_interval: u16,
+    /// Scan window in units of 0.625 ms.
+    pub scan_window: u16,
+    /// Minimum connection interval in units of 1.25 ms.
+    pub interval_min: u16,
+    /// Maximum connection interval in units of 1.25 ms.
+    pub interval_max: u16,
+    /// Maximum peripheral latency in number of connection events.
+    pub max_latency: u16,
+    /// Supervision timeout in units of 10 ms.
+    pub supervision_timeout: u16,
+    /// Minimum connection event length in units of 0.625 ms.
+    pub min_ce_len: u16,
+    /// Maximum connection event length in units of 0.625 ms.
+    pub max_ce_len: u16,
+    /// Initiate on the 1M PHY.
+    pub phy_1m: bool,
+    /// Initiate on the 1M PHY, connecting on the 2M PHY. Requires `phy_1m`.
+    pub phy_2m: bool,
+    /// Initiate on the Coded PHY.
+    pub phy_coded: bool,
+}
+
+impl Default for ConnectParams {
+    fn default() -> Self {
+        Self {
+            own_addr_kind: AddrKind::Public,
+            scan_interval: 96,
+            scan_window: 96,
+            interval_min: 24,
+            interval_max: 40,
+            max_latency: 0,
+            supervision_timeout: 400,
+            min_ce_len: 0,
+            max_ce_len: 0,
+            phy_1m: true,
+            phy_2m: false,
+            phy_coded: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum State {
+    Idle,
+    Connecting,
+    /// The initiating future was dropped, nobody waits for the outcome.
+    Abandoned,
+    /// The outcome of the attempt: the connection handle or an HCI status.
+    Done(Result<u16, u8>),
+}
+
+static STATE: CriticalSectionMutex<Cell<State>> = CriticalSectionMutex::new(Cell::new(State::Idle));
+static WAKER: AtomicWaker = AtomicWaker::new();
+static TAKEN: AtomicBool = AtomicBool::new(false);
+
+pub(crate) fn on_event(event: &Event) {
+    let Event::Le(LeEvent::ConnectionComplete(c)) = event else {
+        return;
+    };
+    // Connected through advertising, or directed advertising timed out.
+    #[cfg(feature = "multirole")]
+    if c.status == hci::STATUS_ADV_TIMEOUT {
+        return;
+    }
+    if c.role != 0 {
+        return;
+    }
+    let result = match c.status {
+        hci::STATUS_SUCCESS => Ok(c.handle),
+        status => Err(status),
+    };
+    let orphan = STATE.lock(|state| match state.get() {
+        State::Connecting => {
+            state.set(State::Done(result));
+            None
+        }
+        State::Abandoned => {
+            state.set(State::Idle);
+            result.ok()
+        }
+        _ => None,
+    });
+    if let Some(handle) = orphan {
+        // The connection completed while the attempt was being cancelled.
+        debug!("[central] disconnecting abandoned connection {}", handle);
+        let _ = conn::disconnect(handle, hci::STATUS_REMOTE_USER_TERMINATED);
+    }
+    WAKER.wake();
+}
+
+fn take_done() -> Option<Result<u16, u8>> {
+    STATE.lock(|state| match state.get() {
+        State::Done(result) => {
+            state.set(State::Idle);
+            Some(result)
+        }
+        _ => None,
+    })
+}
+
+fn abandon() {
+    let done = STATE.lock(|state| match state.get() {
+        State::Connecting => {
+            state.set(State::Abandoned);
+            None
+        }
+        State::Done(result) => {
+            state.set(State::Idle);
+            Some(result)
+        }
+        _ => None,
+    });
+    match done {
+        None => {
+            let _ = unsafe { raw::sdc_hci_cmd_le_create_conn_cancel() };
+        }
+        Some(Ok(handle)) => {
+            let _ = conn::disconnect(handle, hci::STATUS_REMOTE_USER_TERMINATED);
+        }
+        Some(Err(_)) => {}
+    }
+}
+
+/// Initiates connections. Only one central may exist.
+pub struct Central {
+    _private: (),
+}
+
+impl Central {
+    pub fn new() -> Result<Self, Error> {
+        if TAKEN.swap(true, Ordering::AcqRel) {
+            return Err(Error::NotPermitted);
+        }
+        if let Err(e) = hci::enable_le_events(&[hci::SUBEVT_ENHANCED_CONN_COMPLETE]).and_then(|_| conn::enable_events())
+        {
+            TAKEN.store(false, Ordering::Release);
+            return Err(e);
+        }
+        Ok(Self { _private: () })
+    }
+
+    /// Connect to a peripheral, waiting as long as it takes.
+    ///
+    /// Dropping the returned future cancels the attempt, and the next attempt
+    /// starts once the controller has reported the outcome of the cancelled one.
+    pub async fn connect(&mut self, target: Target, params: &ConnectParams) -> Result<Connection, Error> {
+        self.connect_with_timeout(target, params, pending::<()>()).await
+    }
+
+    /// Connect to a peripheral, cancelling the attempt once `timeout` completes,
+    /// for instance an `embassy_time::Timer`.
+    ///
+    /// If the connection is established while the attempt is being cancelled,
+    /// the connection is returned rather than [`Error::Timeout`].
+    pub async fn connect_with_timeout(
+        &mut self,
+        target: Target,
+        params: &ConnectParams,
+        timeout: impl Future,
+    ) -> Result<Connection, Error> {
+        // A cancelled attempt still completes with an event, which must not
+        // complete this one.
+        poll_fn(|cx| {
+            WAKER.register(cx.waker());
+            STATE.lock(|state| match state.get() {
+                State::Idle => {
+                    state.set(State::Connecting);
+                    Poll::Ready(())
+                }
+                _ => Poll::Pending,
+            })
+        })
+        .await;
+        if let Err(e) = create_conn(target, params) {
+            STATE.lock(|state| state.set(State::Idle));
+            return Err(e);
+        }
+        let on_drop = OnDrop::new(abandon);
+
+        let mut timeout = pin!(timeout);
+        let done = poll_fn(|cx| {
+            WAKER.register(cx.waker());
+            if let Some(result) = take_done() {
+                return Poll::Ready(Some(result));
+            }
+            match timeout.as_mut().poll(cx) {
+                Poll::Ready(_) => Poll::Ready(None),
+                Poll::Pending => Poll::Pending,
+            }
+        })
+        .await;
+
+        let result = match done {
+            Some(result) => result,
+            None => {
+                // Command Disallowed means the connection completed in the meantime,
+                // either way the controller reports the outcome in an event.
+                let ret = unsafe { raw::sdc_hci_cmd_le_create_conn_cancel() };
+                if ret != hci::STATUS_SUCCESS && ret != hci::STATUS_COMMAND_DISALLOWED {
+                    warn!("[central] cancel failed: {}", ret);
+                }
+                poll_fn(|cx| {
+                    WAKER.register(cx.waker());
+                    match take_done() {
+                        Some(result) => Poll::Ready(result),
+                        None => Poll::Pending,
+                    }
+                })
+                .await
+            }
+        };
+        on_drop.defuse();
+
+        match result {
+            Ok(handle) => Connection::from_handle(handle).map_err(|_| {
+                // Not tracked, the connection table is full.
+                let _ = conn::disconnect(handle, hci::STATUS_REMOTE_USER_TERMINATED);
+                Error::NoMem
+            }),
+            Err(hci::STATUS_UNKNOWN_CONN_ID) => Err(Error::Timeout),
+            Err(status) => Err(Error::Hci(status)),
+        }
+    }
+}
+
+impl Drop for Central {
+    fn drop(&mut self) {
+        TAKEN.store(false, Ordering::Release);
+    }
+}
+
+fn create_conn(target: Target, params: &ConnectParams) -> Result<(), Error> {
+    if !params.phy_1m && (params.phy_2m || !params.phy_coded) {
+        return Err(Error::InvalidArg);
+    }
+
+    // Filter policy, own address, peer address and PHYs, followed by the
+    // parameters for each PHY.
+    let mut buf = [0; 10 + 3 * 16];
+    let peer = match target {
+        Target::Peer(peer) => peer,
+        Target::AcceptList => {
+            buf[0] = 1;
+            Address {
+                kind: AddrKind::Public,
+                addr: [0; 6],
+            }
+        }
+    };
+    buf[1] = params.own_addr_kind as u8;
+    buf[2] = peer.kind as u8;
+    buf[3..9].copy_from_slice(&peer.addr);
+    let mut len = 10;
+    for (enabled, bit) in [(params.phy_1m, 0x01), (params.phy_2m, 0x02), (params.phy_coded, 0x04)] {
+        if !enabled {
+            continue;
+        }
+        buf[9] |= bit;
+        for (i, value) in [
+            params.scan_interval,
+            params.scan_window,
+            params.interval_min,
+            params.interval_max,
+            params.max_latency,
+            params.supervision_timeout,
+            params.min_ce_len,
+            params.max_ce_len,
+        ]
+        .iter()
+        .enumerate()
+        {
+            buf[len + 2 * i..len + 2 * i + 2].copy_from_slice(&value.to_le_bytes());
+        }
+        len += 16;
+    }
+    hci::status(unsafe { raw::sdc_hci_cmd_le_ext_create_conn(buf.as_ptr() as *const _) })
+}
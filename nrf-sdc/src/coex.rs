@@ -0,0 +1,302 @@
+//! Coexistence with other radios through a Packet Traffic Arbiter (PTA),
+//! such as a Wi-Fi or LTE module next to the nRF.
+//!
+//! MPSL drives the coexistence lines with GPIOTE and PPI, or DPPI on the
+//! nRF5340, so each line takes ownership of its pin and channels for as long
+//! as the interface is enabled. Per-role priorities and the scanner request
+//! mode are set through the controller and can be changed at any time.
+//! Arbiters implemented in software use [`cx`](crate::cx) instead of the GPIO
+//! interfaces.
+use super::hci;
+use super::raw;
+use super::Error;
+use core::future::poll_fn;
+use core::marker::PhantomData;
+use core::sync::atomic::{AtomicU8, Ordering};
+use core::task::Poll;
+use embassy_nrf::gpio::Pin;
+use embassy_nrf::ppi::ConfigurableChannel;
+use embassy_nrf::{gpiote, into_ref, Peripheral};
+use embassy_sync::waitqueue::AtomicWaker;
+
+/// Denied requests are never escalated to high priority.
+const ESCALATION_THRESHOLD_OFF: u8 = 0xFF;
+
+/// Enable and disable operations started and not completed yet. MPSL completes
+/// them in order, so an enable is done once everything before it completed,
+/// including a disable started by dropping the previous [`Coex`].
+static PENDING: AtomicU8 = AtomicU8::new(0);
+static WAKER: AtomicWaker = AtomicWaker::new();
+
+unsafe extern "C" fn on_complete() {
+    PENDING.fetch_sub(1, Ordering::Release);
+    WAKER.wake();
+}
+
+fn start(op: impl FnOnce() -> i32) -> Result<(), Error> {
+    PENDING.fetch_add(1, Ordering::AcqRel);
+    let ret = op();
+    if ret != 0 {
+        PENDING.fetch_sub(1, Ordering::Release);
+        return Err(ret.into());
+    }
+    Ok(())
+}
+
+async fn wait_complete() {
+    poll_fn(|cx| {
+        WAKER.register(cx.waker());
+        match PENDING.load(Ordering::Acquire) {
+            0 => Poll::Ready(()),
+            _ => Poll::Pending,
+        }
+    })
+    .await
+}
+
+/// One line of a GPIO coexistence interface.
+pub struct CoexLine<'d> {
+    cfg: raw::mpsl_coex_gpiote_cfg_t,
+    _p: PhantomData<&'d mut ()>,
+}
+
+impl<'d> CoexLine<'d> {
+    /// A line on `pin`, driven or sampled through `gpiote_ch` and `ppi_ch`.
+    pub fn new(
+        pin: impl Peripheral<P = impl Pin> + 'd,
+        gpiote_ch: impl Peripheral<P = impl gpiote::Channel> + 'd,
+        ppi_ch: impl Peripheral<P = impl ConfigurableChannel> + 'd,
+        active_high: bool,
+    ) -> Self {
+        into_ref!(pin, gpiote_ch, ppi_ch);
+        Self {
+            cfg: raw::mpsl_coex_gpiote_cfg_t {
+                gpio_pin: pin.psel_bits() as u8,
+                active_high: active_high as u8,
+                ppi_ch_id: ppi_ch.number() as u8,
+                gpiote_ch_id: gpiote_ch.number() as u8,
+            },
+            _p: PhantomData,
+        }
+    }
+}
+
+/// A TIMER not used by MPSL, timing the lines of the 3-wire interface.
+pub trait CoexTimer: Peripheral<P = Self> + 'static {
+    #[doc(hidden)]
+    fn regs() -> *mut raw::ctypes::c_void;
+}
+
+// No TIMER of the nRF54L is supported yet.
+#[cfg(not(feature = "nrf54l15"))]
+macro_rules! impl_timer {
+    ($type:ident) => {
+        impl CoexTimer for embassy_nrf::peripherals::$type {
+            fn regs() -> *mut raw::ctypes::c_void {
+                embassy_nrf::pac::$type::ptr() as *mut _
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "nrf54l15"))]
+impl_timer!(TIMER1);
+#[cfg(not(feature = "nrf54l15"))]
+impl_timer!(TIMER2);
+#[cfg(any(
+    feature = "nrf52820",
+    feature = "nrf52832",
+    feature = "nrf52833",
+    feature = "nrf52840"
+))]
+impl_timer!(TIMER3);
+#[cfg(any(feature = "nrf52832", feature = "nrf52833", feature = "nrf52840"))]
+impl_timer!(TIMER4);
+
+/// 3-wire interface based on IEEE 802.15.2-2003, subclause 6.
+pub struct ThreeWireConfig {
+    /// Level of the PRIORITY line signalling RX, the opposite level signals TX.
+    pub rx_active_high: bool,
+    /// Delay in µs from raising REQUEST until PRIORITY shows the type of transaction.
+    pub type_delay_us: u32,
+    /// Delay in µs from PRIORITY showing the type of transaction until the
+    /// radio starts its on-air activity.
+    pub radio_delay_us: u32,
+}
+
+/// Concurrency allowed with the external radio on the 1-wire interface.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Concurrency {
+    None,
+    /// Receive while the external radio is active.
+    RxOnly,
+}
+
+/// An enabled coexistence interface. Dropping it starts disabling the
+/// interface, use [`disable`](Self::disable) to wait for the lines to be released.
+/// Enabling another interface waits for a disable still in progress.
+pub struct Coex<'d> {
+    enabled: bool,
+    _p: PhantomData<&'d mut ()>,
+}
+
+impl<'d> Coex<'d> {
+    /// Enable the 3-wire interface, where REQUEST asks the PTA for the medium,
+    /// PRIORITY carries the priority and then the direction of the transaction,
+    /// and GRANT answers the request.
+    pub async fn three_wire<T: CoexTimer>(
+        config: &ThreeWireConfig,
+        request: CoexLine<'d>,
+        priority: CoexLine<'d>,
+        grant: CoexLine<'d>,
+        priority_ppi_ch: impl Peripheral<P = impl ConfigurableChannel> + 'd,
+        timer: impl Peripheral<P = T> + 'd,
+    ) -> Result<Self, Error> {
+        into_ref!(priority_ppi_ch, timer);
+        let three_wire = raw::mpsl_coex_802152_3wire_gpiote_if_t {
+            request_cfg: request.cfg,
+            priority_cfg: priority.cfg,
+            grant_cfg: grant.cfg,
+            additional_ppi_ch_id: priority_ppi_ch.number() as u8,
+            _bitfield_align_1: [],
+            _bitfield_1: raw::mpsl_coex_802152_3wire_gpiote_if_t::new_bitfield_1(config.rx_active_high as u8),
+            type_delay_us: config.type_delay_us,
+            radio_delay_us: config.radio_delay_us,
+            p_timer_instance: T::regs(),
+        };
+
+        unsafe { raw::mpsl_coex_support_802152_3wire_gpiote_if() };
+        Self::enable(raw::mpsl_coex_if_t {
+            if_id: raw::mpsl_coex_if_id_t_MPSL_COEX_802152_3WIRE_GPIOTE_ID,
+            interfaces: raw::mpsl_coex_if_t__bindgen_ty_1 {
+                coex_3wire_gpiote: three_wire,
+            },
+        })
+        .await
+    }
+
+    /// Enable the 1-wire interface, where GRANT is asserted while the external
+    /// radio is active.
+    ///
+    /// Advertising events are aborted on denial with this interface, see
+    /// [`set_adv_continue_on_denial`].
+    pub async fn one_wire(grant: CoexLine<'d>, concurrency: Concurrency) -> Result<Self, Error> {
+        let concurrency_mode = match concurrency {
+            Concurrency::None => raw::mpsl_coex_1wire_concurrency_mode_t_MPSL_COEX_1WIRE_CONCURRENCY_NONE,
+            Concurrency::RxOnly => raw::mpsl_coex_1wire_concurrency_mode_t_MPSL_COEX_1WIRE_CONCURRENCY_RX_ONLY,
+        };
+        set_adv_continue_on_denial(false)?;
+
+        unsafe { raw::mpsl_coex_support_1wire_gpiote_if() };
+        Self::enable(raw::mpsl_coex_if_t {
+            if_id: raw::mpsl_coex_if_id_t_MPSL_COEX_1WIRE_GPIOTE_ID,
+            interfaces: raw::mpsl_coex_if_t__bindgen_ty_1 {
+                coex_1wire_gpiote: raw::mpsl_coex_1wire_gpiote_if_t {
+                    grant_cfg: grant.cfg,
+                    concurrency_mode,
+                },
+            },
+        })
+        .await
+    }
+
+    async fn enable(cfg: raw::mpsl_coex_if_t) -> Result<Self, Error> {
+        start(|| unsafe { raw::mpsl_coex_enable(&cfg, Some(on_complete)) })?;
+        wait_complete().await;
+        Ok(Self {
+            enabled: true,
+            _p: PhantomData,
+        })
+    }
+
+    /// Disable the interface, releasing its lines to high impedance.
+    pub async fn disable(mut self) -> Result<(), Error> {
+        self.enabled = false;
+        start(|| unsafe { raw::mpsl_coex_disable(Some(on_complete)) })?;
+        wait_complete().await;
+        Ok(())
+    }
+}
+
+impl<'d> Drop for Coex<'d> {
+    fn drop(&mut self) {
+        if self.enabled {
+            if let Err(e) = start(|| unsafe { raw::mpsl_coex_disable(Some(on_complete)) }) {
+                warn!("[coex] failed to disable: {:?}", e);
+            }
+        }
+    }
+}
+
+/// Whether the advertiser carries on with the advertising event after a
+/// packet is denied, the default, or aborts the event.
+///
+/// Must be `false` with the 1-wire interface.
+pub fn set_adv_continue_on_denial(enable: bool) -> Result<(), Error> {
+    let ret = unsafe { raw::sdc_coex_adv_mode_configure(enable) };
+    if ret != 0 {
+        return Err(ret.into());
+    }
+    Ok(())
+}
+
+/// The Bluetooth roles priorities are configured for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum CoexRole {
+    Advertiser,
+    Scanner,
+    Central,
+    Peripheral,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Priority {
+    Low,
+    High,
+}
+
+/// Request the medium for `role` with `priority`.
+///
+/// With low priority, the priority is escalated to high once more than
+/// `escalation_threshold` requests were denied in a row, until the end of the
+/// advertising event, scan window or connection event. `None` never escalates.
+pub fn set_priority(role: CoexRole, priority: Priority, escalation_threshold: Option<u8>) -> Result<(), Error> {
+    let role = match role {
+        CoexRole::Advertiser => raw::sdc_hci_vs_coex_bt_role_SDC_HCI_VS_COEX_BT_ROLE_ADVERTISER,
+        CoexRole::Scanner => raw::sdc_hci_vs_coex_bt_role_SDC_HCI_VS_COEX_BT_ROLE_SCANNER,
+        CoexRole::Central => raw::sdc_hci_vs_coex_bt_role_SDC_HCI_VS_COEX_BT_ROLE_CENTRAL,
+        CoexRole::Peripheral => raw::sdc_hci_vs_coex_bt_role_SDC_HCI_VS_COEX_BT_ROLE_PERIPHERAL,
+    };
+    let priority = match priority {
+        Priority::Low => raw::MPSL_COEX_PRIORITY_MPSL_COEX_PRIORITY_LOW,
+        Priority::High => raw::MPSL_COEX_PRIORITY_MPSL_COEX_PRIORITY_HIGH,
+    };
+    let params = raw::sdc_hci_cmd_vs_coex_priority_config_t {
+        role: role as u8,
+        priority: priority as u8,
+        escalation_threshold: escalation_threshold.unwrap_or(ESCALATION_THRESHOLD_OFF),
+    };
+    hci::status(unsafe { raw::sdc_hci_cmd_vs_coex_priority_config(&params) })
+}
+
+/// When the scanner requests the medium.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ScanMode {
+    /// As soon as a valid access address is received.
+    RequestOnAccessAddress,
+    /// Only before transmitting.
+    RequestOnTx,
+}
+
+pub fn set_scan_mode(mode: ScanMode) -> Result<(), Error> {
+    let mode = match mode {
+        ScanMode::RequestOnAccessAddress => raw::sdc_hci_vs_coex_scan_mode_SDC_HCI_VS_COEX_SCAN_MODE_REQUEST_ON_AA,
+        ScanMode::RequestOnTx => raw::sdc_hci_vs_coex_scan_mode_SDC_HCI_VS_COEX_SCAN_MODE_REQUEST_ON_TX,
+    };
+    let params = raw::sdc_hci_cmd_vs_coex_scan_mode_config_t { mode: mode as u8 };
+    hci::status(unsafe { raw::sdc_hci_cmd_vs_coex_scan_mode_config(&params) })
+}
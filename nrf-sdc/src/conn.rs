@@ -0,0 +1,630 @@
+//! Connections established by the [`Central`](crate::central::Central) or by
+//! connectable advertising.
+//!
+//! Requires [`sdc_run`](crate::sdc::sdc_run) to be running.
+use super::cte::{self, CteType};
+use super::hci::{self, AddrKind, Address, Event, LeEvent, Phy};
+use super::llpm;
+#[cfg(any(feature = "central", feature = "multirole"))]
+use super::llpm::{IntervalError, IntervalParams};
+#[cfg(feature = "multirole")]
+use super::periodic::{self, PeriodicAdvertiser, PeriodicSync, SyncTransferParams};
+use super::raw;
+use super::security::Encryption;
+#[cfg(any(feature = "central", feature = "multirole"))]
+use super::security::{self, LongTermKey};
+use super::Error;
+use core::cell::RefCell;
+use core::future::poll_fn;
+use core::task::Poll;
+use embassy_sync::blocking_mutex::CriticalSectionMutex;
+use embassy_sync::waitqueue::AtomicWaker;
+
+/// Maximum number of simultaneous connections tracked.
+pub const MAX_CONNECTIONS: usize = 8;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Role {
+    Central = 0,
+    Peripheral = 1,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub(crate) struct ConnInfo {
+    pub(crate) handle: u16,
+    pub(crate) role: Role,
+    pub(crate) peer: Address,
+    pub(crate) interval: u16,
+    pub(crate) latency: u16,
+    pub(crate) supervision_timeout: u16,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ConnUpdateParams {
+    /// Minimum connection interval in units of 1.25 ms.
+    pub interval_min: u16,
+    /// Maximum connection interval in units of 1.25 ms.
+    pub interval_max: u16,
+    /// Maximum peripheral latency in number of connection events.
+    pub max_latency: u16,
+    /// Supervision timeout in units of 10 ms.
+    pub supervision_timeout: u16,
+    /// Minimum connection event length in units of 0.625 ms.
+    pub min_ce_len: u16,
+    /// Maximum connection event length in units of 0.625 ms.
+    pub max_ce_len: u16,
+}
+
+/// Maximum payload sizes and transmission times in use on a connection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct DataLength {
+    pub max_tx_octets: u16,
+    /// Maximum transmission time in microseconds.
+    pub max_tx_time: u16,
+    pub max_rx_octets: u16,
+    /// Maximum reception time in microseconds.
+    pub max_rx_time: u16,
+}
+
+#[derive(Clone, Copy)]
+struct Slot {
+    info: ConnInfo,
+    /// A [`Connection`] exists for this slot.
+    owned: bool,
+    /// The disconnection reason, once disconnected.
+    disconnected: Option<u8>,
+    /// Event length in microseconds, fixed when the connection is established.
+    event_length_us: u32,
+    conn_update: Option<u8>,
+    phy_update: Option<Result<(Phy, Phy), u8>>,
+    data_length: Option<DataLength>,
+    remote_features: Option<Result<[u8; 8], u8>>,
+    encryption: Encryption,
+    encryption_change: Option<Result<Encryption, u8>>,
+    /// A received periodic sync transfer: the sync slot and the service data.
+    #[cfg(feature = "multirole")]
+    sync_transfer: Option<Result<(usize, u16), u8>>,
+}
+
+impl Slot {
+    fn is_live(&self, handle: u16) -> bool {
+        self.info.handle == handle && self.disconnected.is_none()
+    }
+}
+
+static CONNS: CriticalSectionMutex<RefCell<[Option<Slot>; MAX_CONNECTIONS]>> =
+    CriticalSectionMutex::new(RefCell::new([None; MAX_CONNECTIONS]));
+
+#[allow(clippy::declare_interior_mutable_const)]
+const NEW_WAKER: AtomicWaker = AtomicWaker::new();
+static WAKERS: [AtomicWaker; MAX_CONNECTIONS] = [NEW_WAKER; MAX_CONNECTIONS];
+
+/// Enable the events reporting changes on established connections.
+pub(crate) fn enable_events() -> Result<(), Error> {
+    hci::enable_events_page_2(&[hci::EVT_MASK_PAGE_2_ENCRYPTION_CHANGE_V2])?;
+    hci::enable_le_events(&[
+        hci::SUBEVT_CONN_UPDATE_COMPLETE,
+        hci::SUBEVT_READ_REMOTE_FEATURES_COMPLETE,
+        hci::SUBEVT_DATA_LENGTH_CHANGE,
+        hci::SUBEVT_PHY_UPDATE_COMPLETE,
+    ])
+}
+
+pub(crate) fn on_event(event: &Event) {
+    match event {
+        Event::Le(LeEvent::ConnectionComplete(c)) if c.status == hci::STATUS_SUCCESS => {
+            let info = ConnInfo {
+                handle: c.handle,
+                role: if c.role == 0 { Role::Central } else { Role::Peripheral },
+                peer: Address {
+                    kind: AddrKind::from_u8(c.peer_addr_kind).unwrap_or(AddrKind::Random),
+                    addr: c.peer_addr,
+                },
+                interval: c.interval,
+                latency: c.latency,
+                supervision_timeout: c.supervision_timeout,
+            };
+            let stored = CONNS.lock(|conns| {
+                let mut conns = conns.borrow_mut();
+                let slot = conns.iter_mut().find(|c| c.is_none())?;
+                *slot = Some(Slot {
+                    info,
+                    owned: false,
+                    disconnected: None,
+                    event_length_us: llpm::event_length(),
+                    conn_update: None,
+                    phy_update: None,
+                    data_length: None,
+                    remote_features: None,
+                    encryption: Encryption::Off,
+                    encryption_change: None,
+                    #[cfg(feature = "multirole")]
+                    sync_transfer: None,
+                });
+                Some(())
+            });
+            if stored.is_none() {
+                warn!("[conn] no room to track connection {}", c.handle);
+            }
+        }
+        Event::DisconnectionComplete(d) if d.status == hci::STATUS_SUCCESS => {
+            with_slot(d.handle, |slot| {
+                if slot.owned {
+                    slot.disconnected = Some(d.reason);
+                    false
+                } else {
+                    true
+                }
+            });
+        }
+        Event::Le(LeEvent::ConnectionUpdateComplete(u)) => {
+            with_slot(u.handle, |slot| {
+                if u.status == hci::STATUS_SUCCESS {
+                    slot.info.interval = u.interval;
+                    slot.info.latency = u.latency;
+                    slot.info.supervision_timeout = u.supervision_timeout;
+                }
+                slot.conn_update = Some(u.status);
+                false
+            });
+        }
+        Event::Le(LeEvent::PhyUpdateComplete(u)) => {
+            with_slot(u.handle, |slot| {
+                slot.phy_update = Some(match u.status {
+                    hci::STATUS_SUCCESS => Ok((
+                        Phy::from_u8(u.tx_phy).unwrap_or(Phy::Le1M),
+                        Phy::from_u8(u.rx_phy).unwrap_or(Phy::Le1M),
+                    )),
+                    status => Err(status),
+                });
+                false
+            });
+        }
+        Event::Le(LeEvent::DataLengthChange(c)) => {
+            with_slot(c.handle, |slot| {
+                slot.data_length = Some(DataLength {
+                    max_tx_octets: c.max_tx_octets,
+                    max_tx_time: c.max_tx_time,
+                    max_rx_octets: c.max_rx_octets,
+                    max_rx_time: c.max_rx_time,
+                });
+                false
+            });
+        }
+        Event::Le(LeEvent::ReadRemoteFeaturesComplete(f)) => {
+            with_slot(f.handle, |slot| {
+                slot.remote_features = Some(match f.status {
+                    hci::STATUS_SUCCESS => Ok(f.features),
+                    status => Err(status),
+                });
+                false
+            });
+        }
+        Event::EncryptionChange(c) => {
+            with_slot(c.handle, |slot| {
+                if c.status == hci::STATUS_SUCCESS {
+                    slot.encryption = match (c.enabled, c.key_size) {
+                        (0, _) => Encryption::Off,
+                        (_, key_size) => Encryption::On {
+                            key_size: key_size.unwrap_or(16),
+                        },
+                    };
+                    slot.encryption_change = Some(Ok(slot.encryption));
+                } else {
+                    slot.encryption_change = Some(Err(c.status));
+                }
+                false
+            });
+        }
+        Event::EncryptionKeyRefreshComplete(c) => {
+            with_slot(c.handle, |slot| {
+                slot.encryption_change = Some(match c.status {
+                    hci::STATUS_SUCCESS => Ok(slot.encryption),
+                    status => Err(status),
+                });
+                false
+            });
+        }
+        _ => {}
+    }
+}
+
+/// Run `f` on the slot of the live connection `handle` and wake its owner.
+/// The slot is freed if `f` returns true.
+fn with_slot(handle: u16, f: impl FnOnce(&mut Slot) -> bool) {
+    let index = CONNS.lock(|conns| {
+        let mut conns = conns.borrow_mut();
+        let index = conns.iter().position(|s| matches!(s, Some(s) if s.is_live(handle)))?;
+        if f(conns[index].as_mut().unwrap()) {
+            conns[index] = None;
+        }
+        Some(index)
+    });
+    if let Some(index) = index {
+        WAKERS[index].wake();
+    }
+}
+
+/// Table index of the live connection `handle`.
+pub(crate) fn index_of(handle: u16) -> Option<usize> {
+    CONNS.lock(|conns| {
+        conns
+            .borrow()
+            .iter()
+            .position(|s| matches!(s, Some(s) if s.is_live(handle)))
+    })
+}
+
+/// The waker of the task owning the connection at table `index`.
+pub(crate) fn waker(index: usize) -> &'static AtomicWaker {
+    &WAKERS[index]
+}
+
+/// Address of the peer on the live connection `handle`.
+#[cfg(any(feature = "peripheral", feature = "multirole"))]
+pub(crate) fn peer(handle: u16) -> Option<Address> {
+    CONNS.lock(|conns| {
+        conns
+            .borrow()
+            .iter()
+            .flatten()
+            .find(|s| s.is_live(handle))
+            .map(|s| s.info.peer)
+    })
+}
+
+/// Hand a received periodic sync transfer to the owner of the connection.
+///
+/// Returns the index of a sync nobody will claim, to be released by the caller.
+#[cfg(feature = "multirole")]
+pub(crate) fn deliver_sync_transfer(handle: u16, transfer: Result<(usize, u16), u8>) -> Option<usize> {
+    let mut unclaimed = transfer.ok().map(|(index, _)| index);
+    with_slot(handle, |slot| {
+        if slot.owned {
+            unclaimed = match slot.sync_transfer.replace(transfer) {
+                Some(Ok((index, _))) => Some(index),
+                _ => None,
+            };
+        }
+        false
+    });
+    unclaimed
+}
+
+pub(crate) fn disconnect(handle: u16, reason: u8) -> Result<(), Error> {
+    let params = raw::sdc_hci_cmd_lc_disconnect_t {
+        conn_handle: handle,
+        reason,
+    };
+    hci::status(unsafe { raw::sdc_hci_cmd_lc_disconnect(&params) })
+}
+
+/// An established connection.
+///
+/// Dropping the connection disconnects it.
+pub struct Connection {
+    handle: u16,
+    index: usize,
+}
+
+impl Connection {
+    /// Take ownership of an established connection, for instance the one
+    /// reported by [`Terminated::Connected`](crate::adv::Terminated::Connected).
+    ///
+    /// Fails with [`Error::NotPermitted`] if a `Connection` already exists for
+    /// the handle and with [`Error::InvalidArg`] if the handle is not connected.
+    pub fn from_handle(handle: u16) -> Result<Self, Error> {
+        CONNS.lock(|conns| {
+            let mut conns = conns.borrow_mut();
+            let index = conns
+                .iter()
+                .position(|s| matches!(s, Some(s) if s.is_live(handle)))
+                .ok_or(Error::InvalidArg)?;
+            let slot = conns[index].as_mut().unwrap();
+            if slot.owned {
+                return Err(Error::NotPermitted);
+            }
+            slot.owned = true;
+            Ok(Self { handle, index })
+        })
+    }
+
+    pub fn handle(&self) -> u16 {
+        self.handle
+    }
+
+    pub(crate) fn index(&self) -> usize {
+        self.index
+    }
+
+    fn slot<R>(&self, f: impl FnOnce(&mut Slot) -> R) -> R {
+        CONNS.lock(|conns| f(conns.borrow_mut()[self.index].as_mut().unwrap()))
+    }
+
+    fn live_info(&self) -> Option<ConnInfo> {
+        self.slot(|slot| slot.disconnected.is_none().then_some(slot.info))
+    }
+
+    /// Wait until `f` returns a value or the connection is closed.
+    async fn wait<R>(&self, mut f: impl FnMut(&mut Slot) -> Option<R>) -> Result<R, Error> {
+        poll_fn(|cx| {
+            WAKERS[self.index].register(cx.waker());
+            self.slot(|slot| match f(slot) {
+                Some(value) => Poll::Ready(Ok(value)),
+                None if slot.disconnected.is_some() => Poll::Ready(Err(Error::Disconnected)),
+                None => Poll::Pending,
+            })
+        })
+        .await
+    }
+
+    /// Whether the controller still reports the connection as established.
+    pub fn is_connected(&self) -> bool {
+        self.live_info().is_some()
+    }
+
+    /// The reason the connection was closed, `None` while connected.
+    pub fn disconnect_reason(&self) -> Option<u8> {
+        self.slot(|slot| slot.disconnected)
+    }
+
+    pub fn role(&self) -> Role {
+        self.slot(|slot| slot.info.role)
+    }
+
+    pub fn peer(&self) -> Address {
+        self.slot(|slot| slot.info.peer)
+    }
+
+    /// Connection interval in units of 1.25 ms.
+    pub fn interval(&self) -> u16 {
+        self.slot(|slot| slot.info.interval)
+    }
+
+    /// Peripheral latency in number of connection events.
+    pub fn latency(&self) -> u16 {
+        self.slot(|slot| slot.info.latency)
+    }
+
+    /// Supervision timeout in units of 10 ms.
+    pub fn supervision_timeout(&self) -> u16 {
+        self.slot(|slot| slot.info.supervision_timeout)
+    }
+
+    /// Update the connection parameters, waiting for the new parameters to
+    /// take effect.
+    pub async fn update(&mut self, params: &ConnUpdateParams) -> Result<(), Error> {
+        self.slot(|slot| slot.conn_update = None);
+        let cmd = raw::sdc_hci_cmd_le_conn_update_t {
+            conn_handle: self.handle,
+            conn_interval_min: params.interval_min,
+            conn_interval_max: params.interval_max,
+            max_latency: params.max_latency,
+            supervision_timeout: params.supervision_timeout,
+            min_ce_length: params.min_ce_len,
+            max_ce_length: params.max_ce_len,
+        };
+        hci::status(unsafe { raw::sdc_hci_cmd_le_conn_update(&cmd) })?;
+        let status = self.wait(|slot| slot.conn_update.take()).await?;
+        hci::status(status)
+    }
+
+    /// Event length in microseconds, see [`llpm::set_event_length`].
+    pub fn event_length_us(&self) -> u32 {
+        self.slot(|slot| slot.event_length_us)
+    }
+
+    /// Update the connection parameters with the interval in microseconds,
+    /// waiting for the new parameters to take effect. Intervals below 7.5 ms
+    /// need [LLPM](crate::llpm).
+    ///
+    /// Only available to the central. The parameters are checked before they
+    /// are sent, and the reason they were rejected returned.
+    #[cfg(any(feature = "central", feature = "multirole"))]
+    pub async fn update_interval_us(&mut self, params: &IntervalParams) -> Result<(), IntervalError> {
+        let (role, event_length_us) = self.slot(|slot| {
+            slot.conn_update = None;
+            (slot.info.role, slot.event_length_us)
+        });
+        if role != Role::Central {
+            return Err(IntervalError::NotCentral);
+        }
+        llpm::conn_update(self.handle, event_length_us, params)?;
+        let status = self.wait(|slot| slot.conn_update.take()).await?;
+        Ok(hci::status(status)?)
+    }
+
+    /// Request the preferred PHYs, waiting for the procedure to complete.
+    ///
+    /// Returns the TX and RX PHYs in use afterwards, which are not
+    /// necessarily the requested ones.
+    pub async fn set_phy(&mut self, tx: Phy, rx: Phy) -> Result<(Phy, Phy), Error> {
+        self.slot(|slot| slot.phy_update = None);
+        let cmd = raw::sdc_hci_cmd_le_set_phy_t {
+            conn_handle: self.handle,
+            all_phys: 0,
+            tx_phys: 1 << (tx as u8 - 1),
+            rx_phys: 1 << (rx as u8 - 1),
+            phy_options: 0,
+        };
+        hci::status(unsafe { raw::sdc_hci_cmd_le_set_phy(&cmd) })?;
+        self.wait(|slot| slot.phy_update.take()).await?.map_err(Error::Hci)
+    }
+
+    /// The TX and RX PHYs currently in use.
+    pub fn read_phy(&self) -> Result<(Phy, Phy), Error> {
+        let cmd = raw::sdc_hci_cmd_le_read_phy_t {
+            conn_handle: self.handle,
+        };
+        let mut ret = unsafe { core::mem::zeroed::<raw::sdc_hci_cmd_le_read_phy_return_t>() };
+        hci::status(unsafe { raw::sdc_hci_cmd_le_read_phy(&cmd, &mut ret) })?;
+        match (Phy::from_u8(ret.tx_phy), Phy::from_u8(ret.rx_phy)) {
+            (Some(tx), Some(rx)) => Ok((tx, rx)),
+            _ => Err(Error::Other),
+        }
+    }
+
+    /// Request the maximum TX payload size and transmission time in
+    /// microseconds. Use [`data_length_changed`](Self::data_length_changed) to
+    /// wait for the values in effect.
+    pub fn set_data_length(&mut self, tx_octets: u16, tx_time: u16) -> Result<(), Error> {
+        self.slot(|slot| slot.data_length = None);
+        let cmd = raw::sdc_hci_cmd_le_set_data_length_t {
+            conn_handle: self.handle,
+            tx_octets,
+            tx_time,
+        };
+        let mut ret = unsafe { core::mem::zeroed::<raw::sdc_hci_cmd_le_set_data_length_return_t>() };
+        hci::status(unsafe { raw::sdc_hci_cmd_le_set_data_length(&cmd, &mut ret) })
+    }
+
+    /// Wait for the next change of the data length, requested by either side.
+    pub async fn data_length_changed(&mut self) -> Result<DataLength, Error> {
+        self.wait(|slot| slot.data_length.take()).await
+    }
+
+    /// RSSI of the last received packet in dBm.
+    pub fn read_rssi(&self) -> Result<i8, Error> {
+        let cmd = raw::sdc_hci_cmd_sp_read_rssi_t { handle: self.handle };
+        let mut ret = unsafe { core::mem::zeroed::<raw::sdc_hci_cmd_sp_read_rssi_return_t>() };
+        hci::status(unsafe { raw::sdc_hci_cmd_sp_read_rssi(&cmd, &mut ret) })?;
+        Ok(ret.rssi)
+    }
+
+    /// The channel map in use, one bit per data channel.
+    pub fn read_channel_map(&self) -> Result<[u8; 5], Error> {
+        let cmd = raw::sdc_hci_cmd_le_read_channel_map_t {
+            conn_handle: self.handle,
+        };
+        let mut ret = unsafe { core::mem::zeroed::<raw::sdc_hci_cmd_le_read_channel_map_return_t>() };
+        hci::status(unsafe { raw::sdc_hci_cmd_le_read_channel_map(&cmd, &mut ret) })?;
+        Ok(ret.channel_map)
+    }
+
+    /// Read the link layer features supported by the peer.
+    pub async fn read_remote_features(&mut self) -> Result<[u8; 8], Error> {
+        self.slot(|slot| slot.remote_features = None);
+        let cmd = raw::sdc_hci_cmd_le_read_remote_features_t {
+            conn_handle: self.handle,
+        };
+        hci::status(unsafe { raw::sdc_hci_cmd_le_read_remote_features(&cmd) })?;
+        self.wait(|slot| slot.remote_features.take()).await?.map_err(Error::Hci)
+    }
+
+    /// The current encryption state.
+    pub fn encryption(&self) -> Encryption {
+        self.slot(|slot| slot.encryption)
+    }
+
+    /// Start encryption as central, or refresh the key if already encrypted,
+    /// waiting for the procedure to complete.
+    ///
+    /// Fails with [`Error::Hci`] if the peer rejects the key.
+    #[cfg(any(feature = "central", feature = "multirole"))]
+    pub async fn encrypt(&mut self, key: &LongTermKey) -> Result<Encryption, Error> {
+        self.slot(|slot| slot.encryption_change = None);
+        security::enable_encryption(self.handle, key)?;
+        self.encryption_changed().await
+    }
+
+    /// Wait for the next change of the encryption state or key refresh, for
+    /// instance when the central starts encryption on a peripheral connection.
+    pub async fn encryption_changed(&mut self) -> Result<Encryption, Error> {
+        self.wait(|slot| slot.encryption_change.take())
+            .await?
+            .map_err(Error::Hci)
+    }
+
+    /// Send the sync of a periodic advertising train to the peer, tagged with
+    /// the application defined `service_data`.
+    #[cfg(feature = "multirole")]
+    pub fn transfer_sync(&mut self, sync: &PeriodicSync, service_data: u16) -> Result<(), Error> {
+        periodic::sync_transfer(self.handle, sync, service_data)
+    }
+
+    /// Send the sync information of our own periodic advertising to the peer,
+    /// tagged with the application defined `service_data`.
+    #[cfg(feature = "multirole")]
+    pub fn transfer_adv_set_info(&mut self, adv: &PeriodicAdvertiser, service_data: u16) -> Result<(), Error> {
+        periodic::set_info_transfer(self.handle, adv.adv_handle(), service_data)
+    }
+
+    /// Set how periodic sync transfers from the peer are handled.
+    #[cfg(feature = "multirole")]
+    pub fn set_sync_transfer_params(&mut self, params: &SyncTransferParams) -> Result<(), Error> {
+        periodic::set_sync_transfer_params(self.handle, params)
+    }
+
+    /// Wait for the peer to transfer a periodic sync, returning the sync and
+    /// the service data sent along with it.
+    #[cfg(feature = "multirole")]
+    pub async fn sync_transfer_received(&mut self) -> Result<(PeriodicSync, u16), Error> {
+        match self.wait(|slot| slot.sync_transfer.take()).await? {
+            Ok((index, service_data)) => Ok((PeriodicSync::from_index(index), service_data)),
+            Err(periodic::STATUS_MEMORY_CAPACITY_EXCEEDED) => Err(Error::NoMem),
+            Err(status) => Err(Error::Hci(status)),
+        }
+    }
+
+    /// Set the CTE types that may be requested by the peer, and the antenna
+    /// switching pattern used for AoD, empty if only AoA is allowed.
+    pub fn set_cte_transmit_params(&mut self, kinds: &[CteType], pattern: &[u8]) -> Result<(), Error> {
+        cte::set_conn_params(self.handle, kinds, pattern)
+    }
+
+    /// Start or stop responding to CTE requests from the peer.
+    pub fn set_cte_response_enabled(&mut self, enable: bool) -> Result<(), Error> {
+        cte::set_conn_response_enable(self.handle, enable)
+    }
+
+    /// Disconnect with the given reason, waiting for the link to be closed.
+    pub async fn disconnect(&mut self, reason: u8) -> Result<(), Error> {
+        if self.is_connected() {
+            disconnect(self.handle, reason)?;
+        }
+        self.disconnected().await;
+        Ok(())
+    }
+
+    /// Wait for the connection to be closed, returning the reason.
+    pub async fn disconnected(&self) -> u8 {
+        poll_fn(|cx| {
+            WAKERS[self.index].register(cx.waker());
+            match self.disconnect_reason() {
+                Some(reason) => Poll::Ready(reason),
+                None => Poll::Pending,
+            }
+        })
+        .await
+    }
+}
+
+impl Drop for Connection {
+    fn drop(&mut self) {
+        #[cfg(feature = "multirole")]
+        if let Some(Ok((index, _))) = self.slot(|slot| slot.sync_transfer.take()) {
+            periodic::release(index);
+        }
+        let connected = CONNS.lock(|conns| {
+            let mut conns = conns.borrow_mut();
+            let slot = conns[self.index].as_mut().unwrap();
+            if slot.disconnected.is_some() {
+                conns[self.index] = None;
+                false
+            } else {
+                // Freed once the disconnection completes.
+                slot.owned = false;
+                true
+            }
+        });
+        if connected {
+            if let Err(e) = disconnect(self.handle, hci::STATUS_REMOTE_USER_TERMINATED) {
+                warn!("[conn] failed to disconnect {}: {:?}", self.handle, e);
+            }
+        }
+    }
+}
@@ -0,0 +1,170 @@
+//! Constant Tone Extensions (CTE) for direction finding, transmitted with
+//! periodic advertising or in response to CTE requests on connections.
+//!
+//! CTEs are configured through
+//! [`PeriodicAdvertiser::set_cte`](crate::periodic::PeriodicAdvertiser::set_cte) and
+//! [`Connection::set_cte_transmit_params`](crate::conn::Connection::set_cte_transmit_params).
+//! Parameters are checked against the antenna array reported by the controller.
+use super::hci;
+use super::raw;
+use super::Error;
+
+/// Maximum number of antenna IDs in a switching pattern.
+pub const MAX_SWITCHING_PATTERN_LEN: usize = 75;
+
+/// Minimum and maximum CTE length in units of 8 µs.
+#[cfg(feature = "multirole")]
+const MIN_CTE_LEN: u8 = 2;
+#[cfg(feature = "multirole")]
+const MAX_CTE_LEN: u8 = 20;
+/// Maximum number of CTEs in each periodic advertising event.
+#[cfg(feature = "multirole")]
+const MAX_CTE_COUNT: u8 = 16;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum CteType {
+    /// Angle of arrival, sent from a single antenna.
+    AoA = 0,
+    /// Angle of departure with 1 µs antenna switching slots.
+    AoD1us = 1,
+    /// Angle of departure with 2 µs antenna switching slots.
+    AoD2us = 2,
+}
+
+impl CteType {
+    fn is_aod(self) -> bool {
+        self != Self::AoA
+    }
+}
+
+/// The antenna array of the controller.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AntennaInfo {
+    /// Supported switching and sampling rates, as reported by the controller.
+    pub switching_sampling_rates: u8,
+    pub num_antennae: u8,
+    pub max_switching_pattern_len: u8,
+    /// Maximum CTE length in units of 8 µs.
+    pub max_cte_len: u8,
+}
+
+impl AntennaInfo {
+    /// Whether 1 µs switching slots are supported for transmitting AoD CTEs.
+    pub fn supports_aod_1us(&self) -> bool {
+        self.switching_sampling_rates & 0x01 != 0
+    }
+
+    fn check_type(&self, kind: CteType) -> Result<(), Error> {
+        match kind {
+            CteType::AoD1us if !self.supports_aod_1us() => Err(Error::OpNotSupported),
+            _ => Ok(()),
+        }
+    }
+
+    /// Check an AoD antenna switching pattern. AoA CTEs are sent from a single
+    /// antenna and take no pattern.
+    fn check_pattern(&self, aod: bool, pattern: &[u8]) -> Result<(), Error> {
+        if !aod {
+            return match pattern.is_empty() {
+                true => Ok(()),
+                false => Err(Error::InvalidArg),
+            };
+        }
+        let max_len = core::cmp::min(self.max_switching_pattern_len as usize, MAX_SWITCHING_PATTERN_LEN);
+        if pattern.len() < 2 || pattern.len() > max_len {
+            return Err(Error::InvalidArg);
+        }
+        if pattern.iter().any(|&id| id >= self.num_antennae) {
+            return Err(Error::InvalidArg);
+        }
+        Ok(())
+    }
+}
+
+/// Read the antenna array of the controller.
+pub fn antenna_info() -> Result<AntennaInfo, Error> {
+    let mut ret = unsafe { core::mem::zeroed::<raw::sdc_hci_cmd_le_read_antenna_information_return_t>() };
+    hci::status(unsafe { raw::sdc_hci_cmd_le_read_antenna_information(&mut ret) })?;
+    Ok(AntennaInfo {
+        switching_sampling_rates: ret.supported_switching_sampling_rates,
+        num_antennae: ret.num_antennae,
+        max_switching_pattern_len: ret.max_switching_pattern_length,
+        max_cte_len: ret.max_cte_length,
+    })
+}
+
+/// CTEs attached to periodic advertising.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct CteParams<'a> {
+    pub kind: CteType,
+    /// CTE length in units of 8 µs, from 2 to 20.
+    pub len: u8,
+    /// Number of CTEs sent in each periodic advertising event.
+    pub count: u8,
+    /// Antenna IDs to switch through, empty for AoA.
+    pub pattern: &'a [u8],
+}
+
+#[cfg(feature = "multirole")]
+pub(crate) fn set_connless_params(adv_handle: u8, params: &CteParams) -> Result<(), Error> {
+    let info = antenna_info()?;
+    info.check_type(params.kind)?;
+    info.check_pattern(params.kind.is_aod(), params.pattern)?;
+    if params.len < MIN_CTE_LEN || params.len > core::cmp::min(MAX_CTE_LEN, info.max_cte_len) {
+        return Err(Error::InvalidArg);
+    }
+    if params.count == 0 || params.count > MAX_CTE_COUNT {
+        return Err(Error::InvalidArg);
+    }
+
+    let mut buf = [0; 5 + MAX_SWITCHING_PATTERN_LEN];
+    buf[0] = adv_handle;
+    buf[1] = params.len;
+    buf[2] = params.kind as u8;
+    buf[3] = params.count;
+    buf[4] = params.pattern.len() as u8;
+    buf[5..5 + params.pattern.len()].copy_from_slice(params.pattern);
+    hci::status(unsafe { raw::sdc_hci_cmd_le_set_connless_cte_transmit_params(buf.as_ptr() as *const _) })
+}
+
+#[cfg(feature = "multirole")]
+pub(crate) fn set_connless_enable(adv_handle: u8, enable: bool) -> Result<(), Error> {
+    let params = raw::sdc_hci_cmd_le_set_connless_cte_transmit_enable_t {
+        adv_handle,
+        cte_enable: enable as u8,
+    };
+    hci::status(unsafe { raw::sdc_hci_cmd_le_set_connless_cte_transmit_enable(&params) })
+}
+
+pub(crate) fn set_conn_params(conn_handle: u16, kinds: &[CteType], pattern: &[u8]) -> Result<(), Error> {
+    if kinds.is_empty() {
+        return Err(Error::InvalidArg);
+    }
+    let info = antenna_info()?;
+    let mut types = 0;
+    for &kind in kinds {
+        info.check_type(kind)?;
+        types |= 1 << kind as u8;
+    }
+    info.check_pattern(kinds.iter().any(|k| k.is_aod()), pattern)?;
+
+    let mut buf = [0; 4 + MAX_SWITCHING_PATTERN_LEN];
+    buf[0..2].copy_from_slice(&conn_handle.to_le_bytes());
+    buf[2] = types;
+    buf[3] = pattern.len() as u8;
+    buf[4..4 + pattern.len()].copy_from_slice(pattern);
+    let mut ret = unsafe { core::mem::zeroed::<raw::sdc_hci_cmd_le_set_conn_cte_transmit_params_return_t>() };
+    hci::status(unsafe { raw::sdc_hci_cmd_le_set_conn_cte_transmit_params(buf.as_ptr() as *const _, &mut ret) })
+}
+
+pub(crate) fn set_conn_response_enable(conn_handle: u16, enable: bool) -> Result<(), Error> {
+    let params = raw::sdc_hci_cmd_le_conn_cte_response_enable_t {
+        conn_handle,
+        enable: enable as u8,
+    };
+    let mut ret = unsafe { core::mem::zeroed::<raw::sdc_hci_cmd_le_conn_cte_response_enable_return_t>() };
+    hci::status(unsafe { raw::sdc_hci_cmd_le_conn_cte_response_enable(&params, &mut ret) })
+}
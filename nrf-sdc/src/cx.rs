@@ -0,0 +1,342 @@
+//! Custom coexistence implementations behind the MPSL CX interface.
+//!
+//! Instead of one of the GPIO interfaces in [`coex`](crate::coex), MPSL can
+//! ask an application-provided [`CoexInterface`] for access to the medium, for
+//! example to arbitrate against another radio on the same board. The radio
+//! protocols call it from the radio interrupt, so implementations must not block.
+use super::raw;
+use super::Error;
+use core::cell::Cell;
+use core::sync::atomic::{AtomicBool, Ordering};
+use embassy_sync::blocking_mutex::CriticalSectionMutex;
+
+const NRF_EPERM: i32 = raw::NRF_EPERM as i32;
+const NRF_ENOMEM: i32 = raw::NRF_ENOMEM as i32;
+const NRF_EINVAL: i32 = raw::NRF_EINVAL as i32;
+const NRF_EAGAIN: i32 = raw::NRF_EAGAIN as i32;
+const NRF_EOPNOTSUPP: i32 = raw::NRF_EOPNOTSUPP as i32;
+const NRF_ETIMEDOUT: i32 = raw::NRF_ETIMEDOUT as i32;
+/// Not part of the bindings, from `nrf_errno.h`.
+const NRF_EALREADY: i32 = 114;
+
+/// A set of radio operations requested from or granted by the arbiter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Ops(u8);
+
+impl Ops {
+    pub const NONE: Self = Self(0);
+    /// Listening for asynchronous transmissions.
+    pub const IDLE_LISTEN: Self = Self(raw::mpsl_cx_op_t_MPSL_CX_OP_IDLE_LISTEN as u8);
+    /// Active reception.
+    pub const RX: Self = Self(raw::mpsl_cx_op_t_MPSL_CX_OP_RX as u8);
+    /// Active transmission.
+    pub const TX: Self = Self(raw::mpsl_cx_op_t_MPSL_CX_OP_TX as u8);
+    pub const ALL: Self = Self(Self::IDLE_LISTEN.0 | Self::RX.0 | Self::TX.0);
+
+    /// The operations in `bits`, or `None` if unknown bits are set.
+    pub fn from_bits(bits: u8) -> Option<Self> {
+        (bits & !Self::ALL.0 == 0).then_some(Self(bits))
+    }
+
+    pub fn bits(self) -> u8 {
+        self.0
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Whether all operations in `other` are in `self`.
+    pub fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl core::ops::BitOr for Ops {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl core::ops::BitAnd for Ops {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        Self(self.0 & rhs.0)
+    }
+}
+
+/// A request for radio operations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Request {
+    pub ops: Ops,
+    /// Priority of the operations. Protocols sharing the interface use aligned
+    /// values, commonly up to 127 for low and above for high priority.
+    pub priority: u8,
+}
+
+/// An arbiter granting radio operations to the protocols running on MPSL.
+///
+/// Called from the radio interrupt, so calls must not block.
+pub trait CoexInterface: Sync {
+    /// Request `req.ops`, replacing the previous request.
+    fn request(&self, req: &Request) -> Result<(), Error>;
+
+    /// Release the last request.
+    fn release(&self);
+
+    /// The operations currently granted. Call [`notify_granted`] when this changes.
+    fn granted(&self) -> Ops;
+
+    /// Time in µs the arbiter needs to answer a request.
+    fn grant_delay_us(&self) -> u32;
+}
+
+static INTERFACE: CriticalSectionMutex<Cell<Option<&'static dyn CoexInterface>>> =
+    CriticalSectionMutex::new(Cell::new(None));
+static CALLBACK: CriticalSectionMutex<Cell<raw::mpsl_cx_cb_t>> = CriticalSectionMutex::new(Cell::new(None));
+static REQUESTED: AtomicBool = AtomicBool::new(false);
+
+static METHODS: raw::mpsl_cx_interface_t = raw::mpsl_cx_interface_t {
+    p_request: Some(request),
+    p_release: Some(release),
+    p_granted_ops_get: Some(granted_ops_get),
+    p_req_grant_delay_get: Some(req_grant_delay_get),
+    p_register_callback: Some(register_callback),
+};
+
+/// Use `iface` for coexistence. Must be called after
+/// [`mpsl_init`](crate::mpsl::mpsl_init) and before the radio protocols start.
+pub fn set_interface(iface: &'static dyn CoexInterface) -> Result<(), Error> {
+    install(iface);
+    let ret = unsafe { raw::mpsl_cx_interface_set(&METHODS) };
+    if ret != 0 {
+        return Err(ret.into());
+    }
+    Ok(())
+}
+
+fn install(iface: &'static dyn CoexInterface) {
+    INTERFACE.lock(|i| i.set(Some(iface)));
+    CALLBACK.lock(|c| c.set(None));
+    REQUESTED.store(false, Ordering::Relaxed);
+}
+
+/// Tell the radio protocol that the granted operations changed to `granted`.
+pub fn notify_granted(granted: Ops) {
+    if let Some(cb) = CALLBACK.lock(|c| c.get()) {
+        unsafe { cb(granted.bits()) };
+    }
+}
+
+fn interface() -> Option<&'static dyn CoexInterface> {
+    INTERFACE.lock(|i| i.get())
+}
+
+fn errno(error: Error) -> i32 {
+    -match error {
+        Error::InvalidArg => NRF_EINVAL,
+        Error::OpNotSupported => NRF_EOPNOTSUPP,
+        Error::Again => NRF_EAGAIN,
+        Error::NoMem => NRF_ENOMEM,
+        Error::Timeout => NRF_ETIMEDOUT,
+        _ => NRF_EPERM,
+    }
+}
+
+unsafe extern "C" fn request(p_req_params: *const raw::mpsl_cx_request_t) -> i32 {
+    let Some(params) = p_req_params.as_ref() else {
+        return -NRF_EINVAL;
+    };
+    let Some(ops) = Ops::from_bits(params.ops) else {
+        return -NRF_EINVAL;
+    };
+    if params.trigger != raw::mpsl_cx_req_trig_t_MPSL_CX_REQ_TRIG_INSTANT {
+        return -NRF_EINVAL;
+    }
+    let Some(iface) = interface() else {
+        return -NRF_EPERM;
+    };
+    let req = Request {
+        ops,
+        priority: params.prio,
+    };
+    match iface.request(&req) {
+        Ok(()) => {
+            REQUESTED.store(true, Ordering::Relaxed);
+            0
+        }
+        Err(e) => errno(e),
+    }
+}
+
+unsafe extern "C" fn release() -> i32 {
+    if !REQUESTED.swap(false, Ordering::Relaxed) {
+        return -NRF_EALREADY;
+    }
+    if let Some(iface) = interface() {
+        iface.release();
+    }
+    0
+}
+
+unsafe extern "C" fn granted_ops_get(p_granted_ops: *mut raw::mpsl_cx_op_map_t) -> i32 {
+    let Some(granted_ops) = p_granted_ops.as_mut() else {
+        return -NRF_EINVAL;
+    };
+    *granted_ops = interface().map_or(Ops::NONE, |i| i.granted()).bits();
+    0
+}
+
+unsafe extern "C" fn req_grant_delay_get() -> u32 {
+    interface().map_or(0, |i| i.grant_delay_us())
+}
+
+unsafe extern "C" fn register_callback(cb: raw::mpsl_cx_cb_t) -> i32 {
+    CALLBACK.lock(|c| c.set(cb));
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::sync::atomic::AtomicU8;
+    use std::sync::Mutex;
+
+    /// Grants TX only above a priority threshold, everything else always.
+    struct Arbiter {
+        granted: AtomicU8,
+        releases: AtomicU8,
+    }
+
+    impl CoexInterface for Arbiter {
+        fn request(&self, req: &Request) -> Result<(), Error> {
+            if req.ops.is_empty() {
+                return Err(Error::InvalidArg);
+            }
+            let mut granted = req.ops;
+            if req.priority <= 127 {
+                granted = granted & (Ops::IDLE_LISTEN | Ops::RX);
+            }
+            self.granted.store(granted.bits(), Ordering::Relaxed);
+            notify_granted(granted);
+            Ok(())
+        }
+
+        fn release(&self) {
+            self.granted.store(0, Ordering::Relaxed);
+            self.releases.fetch_add(1, Ordering::Relaxed);
+        }
+
+        fn granted(&self) -> Ops {
+            Ops::from_bits(self.granted.load(Ordering::Relaxed)).unwrap()
+        }
+
+        fn grant_delay_us(&self) -> u32 {
+            40
+        }
+    }
+
+    static ARBITER: Arbiter = Arbiter {
+        granted: AtomicU8::new(0),
+        releases: AtomicU8::new(0),
+    };
+    static NOTIFIED: AtomicU8 = AtomicU8::new(0xFF);
+    // The adapter is global, run the tests one at a time.
+    static LOCK: Mutex<()> = Mutex::new(());
+
+    unsafe extern "C" fn on_granted(ops: raw::mpsl_cx_op_map_t) {
+        NOTIFIED.store(ops, Ordering::Relaxed);
+    }
+
+    fn setup() -> std::sync::MutexGuard<'static, ()> {
+        let guard = LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        install(&ARBITER);
+        ARBITER.granted.store(0, Ordering::Relaxed);
+        ARBITER.releases.store(0, Ordering::Relaxed);
+        NOTIFIED.store(0xFF, Ordering::Relaxed);
+        guard
+    }
+
+    fn req(ops: Ops, prio: u8) -> raw::mpsl_cx_request_t {
+        raw::mpsl_cx_request_t {
+            ops: ops.bits(),
+            prio,
+            trigger: raw::mpsl_cx_req_trig_t_MPSL_CX_REQ_TRIG_INSTANT,
+        }
+    }
+
+    fn granted() -> u8 {
+        let mut ops = 0xFF;
+        assert_eq!(unsafe { (METHODS.p_granted_ops_get.unwrap())(&mut ops) }, 0);
+        ops
+    }
+
+    #[test]
+    fn arbitrates_by_priority() {
+        let _guard = setup();
+        let request = METHODS.p_request.unwrap();
+
+        assert_eq!(unsafe { request(&req(Ops::RX | Ops::TX, 10)) }, 0);
+        assert_eq!(granted(), Ops::RX.bits());
+
+        assert_eq!(unsafe { request(&req(Ops::RX | Ops::TX, 200)) }, 0);
+        assert_eq!(granted(), (Ops::RX | Ops::TX).bits());
+        assert_eq!(unsafe { (METHODS.p_req_grant_delay_get.unwrap())() }, 40);
+    }
+
+    #[test]
+    fn release_once_per_request() {
+        let _guard = setup();
+        let release = METHODS.p_release.unwrap();
+
+        assert_eq!(unsafe { release() }, -NRF_EALREADY);
+        assert_eq!(unsafe { (METHODS.p_request.unwrap())(&req(Ops::TX, 200)) }, 0);
+        assert_eq!(unsafe { release() }, 0);
+        assert_eq!(unsafe { release() }, -NRF_EALREADY);
+        assert_eq!(ARBITER.releases.load(Ordering::Relaxed), 1);
+        assert_eq!(granted(), 0);
+    }
+
+    #[test]
+    fn rejects_invalid_requests() {
+        let _guard = setup();
+        let request = METHODS.p_request.unwrap();
+
+        assert_eq!(unsafe { request(core::ptr::null()) }, -NRF_EINVAL);
+        let mut unknown = req(Ops::TX, 200);
+        unknown.ops = 0x08;
+        assert_eq!(unsafe { request(&unknown) }, -NRF_EINVAL);
+        let mut delayed = req(Ops::TX, 200);
+        delayed.trigger = 1;
+        assert_eq!(unsafe { request(&delayed) }, -NRF_EINVAL);
+        // Errors from the interface are passed on, and nothing is left to release.
+        assert_eq!(unsafe { request(&req(Ops::NONE, 200)) }, -NRF_EINVAL);
+        assert_eq!(unsafe { (METHODS.p_release.unwrap())() }, -NRF_EALREADY);
+        assert_eq!(
+            unsafe { (METHODS.p_granted_ops_get.unwrap())(core::ptr::null_mut()) },
+            -NRF_EINVAL
+        );
+    }
+
+    #[test]
+    fn notifies_registered_callback() {
+        let _guard = setup();
+        let request = METHODS.p_request.unwrap();
+
+        assert_eq!(unsafe { request(&req(Ops::TX, 200)) }, 0);
+        assert_eq!(NOTIFIED.load(Ordering::Relaxed), 0xFF);
+
+        assert_eq!(unsafe { (METHODS.p_register_callback.unwrap())(Some(on_granted)) }, 0);
+        assert_eq!(unsafe { request(&req(Ops::RX | Ops::TX, 10)) }, 0);
+        assert_eq!(NOTIFIED.load(Ordering::Relaxed), Ops::RX.bits());
+
+        assert_eq!(unsafe { (METHODS.p_register_callback.unwrap())(None) }, 0);
+        notify_granted(Ops::ALL);
+        assert_eq!(NOTIFIED.load(Ordering::Relaxed), Ops::RX.bits());
+    }
+}
@@ -0,0 +1,808 @@
+//! Direct Test Mode (DTM) for RF certification, speaking the 2-wire UART
+//! protocol of the Bluetooth Core Specification, Vol 6, Part F, on top of the
+//! controller's test commands.
+//!
+//! The tester sends 2-byte commands, most significant byte first, and each of
+//! them is answered with a 2-byte [`Response`] from [`Dtm::process`]. The UART
+//! is left to the application, see the `dtm` example. Command Complete events
+//! are read directly from the controller, so [`sdc_run`](crate::sdc::sdc_run)
+//! must not be running.
+use super::hci::{self, Event, Reader};
+use super::raw;
+use super::sdc;
+use super::tx_power;
+use super::Error;
+
+/// Number of RF channels, channel `n` being at 2402 + 2n MHz.
+pub const NUM_RF_CHANNELS: u8 = 40;
+
+/// Maximum number of antennas in a switching pattern.
+pub const MAX_ANTENNAS: u8 = 75;
+
+const CMD_SETUP: u16 = 0b00;
+const CMD_RECEIVER_TEST: u16 = 0b01;
+const CMD_TRANSMITTER_TEST: u16 = 0b10;
+
+const CTRL_RESET: u8 = 0x00;
+const CTRL_LENGTH_UPPER_BITS: u8 = 0x01;
+const CTRL_PHY: u8 = 0x02;
+const CTRL_MODULATION_INDEX: u8 = 0x03;
+const CTRL_READ_SUPPORTED_FEATURES: u8 = 0x04;
+const CTRL_READ_MAX_VALUE: u8 = 0x05;
+const CTRL_CTE: u8 = 0x06;
+const CTRL_CTE_SLOT_DURATION: u8 = 0x07;
+const CTRL_ANTENNAS: u8 = 0x08;
+const CTRL_TX_POWER: u8 = 0x09;
+
+/// CTE lengths in units of 8 µs.
+const CTE_LENGTH_MIN: u8 = 2;
+const CTE_LENGTH_MAX: u8 = 20;
+
+const TX_POWER_MIN_DBM: i8 = -127;
+const TX_POWER_MAX_DBM: i8 = 20;
+const TX_POWER_MIN: i8 = 0x7E;
+const TX_POWER_MAX: i8 = 0x7F;
+
+const OPCODE_RECEIVER_TEST_V1: u16 = 0x201D;
+const OPCODE_TRANSMITTER_TEST_V1: u16 = 0x201E;
+const OPCODE_RECEIVER_TEST_V2: u16 = 0x2033;
+const OPCODE_TRANSMITTER_TEST_V2: u16 = 0x2034;
+const OPCODE_RECEIVER_TEST_V3: u16 = 0x204F;
+const OPCODE_TRANSMITTER_TEST_V3: u16 = 0x2050;
+const OPCODE_TRANSMITTER_TEST_V4: u16 = 0x207B;
+
+/// Bits of the LE features reported by the Read Supported Features setup command.
+const LE_FEATURE_DATA_LENGTH_EXTENSION: u64 = 1 << 5;
+const LE_FEATURE_2M_PHY: u64 = 1 << 8;
+const LE_FEATURE_STABLE_MODULATION_INDEX_TX: u64 = 1 << 9;
+const LE_FEATURE_CODED_PHY: u64 = 1 << 11;
+const LE_FEATURE_CONNECTIONLESS_CTE_TX: u64 = 1 << 19;
+const LE_FEATURE_RECEIVING_CTE: u64 = 1 << 23;
+
+const DTM_FEATURE_DATA_LENGTH_EXTENSION: u16 = 1 << 0;
+const DTM_FEATURE_2M_PHY: u16 = 1 << 1;
+const DTM_FEATURE_STABLE_MODULATION_INDEX_TX: u16 = 1 << 2;
+const DTM_FEATURE_CODED_PHY: u16 = 1 << 3;
+const DTM_FEATURE_CTE: u16 = 1 << 4;
+
+/// Payload of transmitted test packets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Payload {
+    /// Pseudo-random bit sequence 9.
+    Prbs9,
+    /// Repeated `11110000`.
+    Pattern11110000,
+    /// Repeated `10101010`.
+    Pattern10101010,
+    /// Repeated `11111111`, the vendor specific packet type.
+    AllOnes,
+}
+
+impl Payload {
+    fn from_bits(bits: u8) -> Self {
+        match bits & 0x03 {
+            0 => Self::Prbs9,
+            1 => Self::Pattern11110000,
+            2 => Self::Pattern10101010,
+            _ => Self::AllOnes,
+        }
+    }
+
+    fn raw(self) -> u8 {
+        match self {
+            Self::Prbs9 => 0x00,
+            Self::Pattern11110000 => 0x01,
+            Self::Pattern10101010 => 0x02,
+            Self::AllOnes => 0x04,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Phy {
+    Le1M,
+    Le2M,
+    /// Coded PHY with S=8 coding.
+    LeCodedS8,
+    /// Coded PHY with S=2 coding.
+    LeCodedS2,
+}
+
+impl Phy {
+    fn tx(self) -> u8 {
+        match self {
+            Self::Le1M => 0x01,
+            Self::Le2M => 0x02,
+            Self::LeCodedS8 => 0x03,
+            Self::LeCodedS2 => 0x04,
+        }
+    }
+
+    /// The receiver handles both coding schemes.
+    fn rx(self) -> u8 {
+        match self {
+            Self::Le1M => 0x01,
+            Self::Le2M => 0x02,
+            Self::LeCodedS8 | Self::LeCodedS2 => 0x03,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ModulationIndex {
+    Standard,
+    Stable,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum CteType {
+    /// Angle of arrival.
+    AoA,
+    /// Angle of departure with 1 µs switching slots.
+    AoD1us,
+    /// Angle of departure with 2 µs switching slots.
+    AoD2us,
+}
+
+/// Constant Tone Extension appended to test packets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Cte {
+    /// Length in units of 8 µs, 2 to 20.
+    pub length: u8,
+    pub kind: CteType,
+}
+
+/// Switching and sampling slot duration of the receiver for AoA.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum SlotDuration {
+    Slot1us,
+    Slot2us,
+}
+
+/// Order the antennas are switched in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum SwitchingPattern {
+    /// 1, 2, 3 ... N, 1, 2, 3 ...
+    Repeat,
+    /// 1, 2, 3 ... N, N-1 ... 2, 1, 2 ...
+    Mirror,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum TxPowerRequest {
+    /// The lowest level supported.
+    Min,
+    /// The highest level supported.
+    Max,
+    /// The highest level not above the given one, in dBm.
+    Dbm(i8),
+}
+
+impl TxPowerRequest {
+    fn raw(self) -> i8 {
+        match self {
+            Self::Min => TX_POWER_MIN,
+            Self::Max => TX_POWER_MAX,
+            Self::Dbm(dbm) => dbm,
+        }
+    }
+}
+
+/// Limits read with [`Setup::ReadMaxValue`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum MaxValue {
+    TxOctets,
+    /// Transmit time in µs.
+    TxTime,
+    RxOctets,
+    /// Receive time in µs.
+    RxTime,
+}
+
+/// Test setup commands, configuring the following tests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Setup {
+    /// End any test and go back to the default setup.
+    Reset,
+    /// Bits 7 and 6 of the packet length of transmitter tests.
+    SetLengthUpperBits(u8),
+    SetPhy(Phy),
+    SetModulationIndex(ModulationIndex),
+    ReadSupportedFeatures,
+    ReadMaxValue(MaxValue),
+    /// Constant Tone Extension of the tests, `None` for none.
+    SetCte(Option<Cte>),
+    SetSlotDuration(SlotDuration),
+    SetAntennas {
+        count: u8,
+        pattern: SwitchingPattern,
+    },
+    SetTxPower(TxPowerRequest),
+}
+
+impl Setup {
+    fn decode(control: u8, param: u8) -> Option<Self> {
+        // The parameter of the original setup commands is followed by two
+        // don't care bits, the later ones use all of it.
+        let short = param >> 2;
+        let setup = match control {
+            CTRL_RESET if short == 0 => Self::Reset,
+            CTRL_LENGTH_UPPER_BITS if short <= 0x03 => Self::SetLengthUpperBits(short),
+            CTRL_PHY => Self::SetPhy(match short {
+                0x01 => Phy::Le1M,
+                0x02 => Phy::Le2M,
+                0x03 => Phy::LeCodedS8,
+                0x04 => Phy::LeCodedS2,
+                _ => return None,
+            }),
+            CTRL_MODULATION_INDEX => Self::SetModulationIndex(match short {
+                0x00 => ModulationIndex::Standard,
+                0x01 => ModulationIndex::Stable,
+                _ => return None,
+            }),
+            CTRL_READ_SUPPORTED_FEATURES if short == 0 => Self::ReadSupportedFeatures,
+            CTRL_READ_MAX_VALUE => Self::ReadMaxValue(match short {
+                0x00 => MaxValue::TxOctets,
+                0x01 => MaxValue::TxTime,
+                0x02 => MaxValue::RxOctets,
+                0x03 => MaxValue::RxTime,
+                _ => return None,
+            }),
+            CTRL_CTE if param == 0 => Self::SetCte(None),
+            CTRL_CTE => {
+                let length = param & 0x3F;
+                if !(CTE_LENGTH_MIN..=CTE_LENGTH_MAX).contains(&length) {
+                    return None;
+                }
+                let kind = match param >> 6 {
+                    0 => CteType::AoA,
+                    1 => CteType::AoD1us,
+                    2 => CteType::AoD2us,
+                    _ => return None,
+                };
+                Self::SetCte(Some(Cte { length, kind }))
+            }
+            CTRL_CTE_SLOT_DURATION => Self::SetSlotDuration(match param {
+                0x01 => SlotDuration::Slot1us,
+                0x02 => SlotDuration::Slot2us,
+                _ => return None,
+            }),
+            CTRL_ANTENNAS => {
+                let count = param & 0x7F;
+                if !(1..=MAX_ANTENNAS).contains(&count) {
+                    return None;
+                }
+                let pattern = match param >> 7 {
+                    0 => SwitchingPattern::Repeat,
+                    _ => SwitchingPattern::Mirror,
+                };
+                Self::SetAntennas { count, pattern }
+            }
+            CTRL_TX_POWER => Self::SetTxPower(match param as i8 {
+                TX_POWER_MIN => TxPowerRequest::Min,
+                TX_POWER_MAX => TxPowerRequest::Max,
+                dbm if (TX_POWER_MIN_DBM..=TX_POWER_MAX_DBM).contains(&dbm) => TxPowerRequest::Dbm(dbm),
+                _ => return None,
+            }),
+            _ => return None,
+        };
+        Some(setup)
+    }
+}
+
+/// A command from the tester.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Command {
+    Setup(Setup),
+    ReceiverTest {
+        channel: u8,
+    },
+    TransmitterTest {
+        channel: u8,
+        /// Bits 5 to 0 of the packet length, completed by [`Setup::SetLengthUpperBits`].
+        length: u8,
+        payload: Payload,
+    },
+    TestEnd,
+}
+
+impl Command {
+    /// Decode a command, `None` if it is malformed or a parameter is out of range.
+    pub fn decode(cmd: u16) -> Option<Self> {
+        let control = ((cmd >> 8) & 0x3F) as u8;
+        let param = cmd as u8;
+        match cmd >> 14 {
+            CMD_SETUP => Setup::decode(control, param).map(Self::Setup),
+            // The length and packet type of receiver tests are ignored.
+            CMD_RECEIVER_TEST if control < NUM_RF_CHANNELS => Some(Self::ReceiverTest { channel: control }),
+            CMD_TRANSMITTER_TEST if control < NUM_RF_CHANNELS => Some(Self::TransmitterTest {
+                channel: control,
+                length: param >> 2,
+                payload: Payload::from_bits(param),
+            }),
+            CMD_RECEIVER_TEST | CMD_TRANSMITTER_TEST => None,
+            _ => Some(Self::TestEnd),
+        }
+    }
+
+    /// Decode a command from the two bytes received on the UART.
+    pub fn from_bytes(bytes: [u8; 2]) -> Option<Self> {
+        Self::decode(u16::from_be_bytes(bytes))
+    }
+}
+
+/// The answer to a command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Response {
+    Success,
+    Failure,
+    /// Success with the value read by a setup command.
+    Value(u16),
+    /// Packets received by a receiver test, answering [`Command::TestEnd`].
+    /// Zero after a transmitter test.
+    Packets(u16),
+}
+
+impl Response {
+    /// The 16-bit event sent to the tester.
+    ///
+    /// Values sit above the status bit. Times above 16383 µs spill into the
+    /// event bit, as with Nordic's DTM firmware.
+    pub fn encode(self) -> u16 {
+        match self {
+            Self::Success => 0x0000,
+            Self::Failure => 0x0001,
+            Self::Value(value) => value << 1,
+            Self::Packets(count) => 0x8000 | (count & 0x7FFF),
+        }
+    }
+
+    /// The two bytes to send on the UART.
+    pub fn to_bytes(self) -> [u8; 2] {
+        self.encode().to_be_bytes()
+    }
+}
+
+/// The DTM features of a controller with the given LE features.
+fn features(le_features: u64) -> u16 {
+    [
+        (LE_FEATURE_DATA_LENGTH_EXTENSION, DTM_FEATURE_DATA_LENGTH_EXTENSION),
+        (LE_FEATURE_2M_PHY, DTM_FEATURE_2M_PHY),
+        (
+            LE_FEATURE_STABLE_MODULATION_INDEX_TX,
+            DTM_FEATURE_STABLE_MODULATION_INDEX_TX,
+        ),
+        (LE_FEATURE_CODED_PHY, DTM_FEATURE_CODED_PHY),
+        (
+            LE_FEATURE_CONNECTIONLESS_CTE_TX | LE_FEATURE_RECEIVING_CTE,
+            DTM_FEATURE_CTE,
+        ),
+    ]
+    .into_iter()
+    .filter(|(le, _)| le_features & le != 0)
+    .fold(0, |dtm, (_, bit)| dtm | bit)
+}
+
+/// Fill `out` with the antenna IDs of the switching pattern, returning them.
+fn antenna_ids(count: u8, pattern: SwitchingPattern, out: &mut [u8; MAX_ANTENNAS as usize]) -> &[u8] {
+    let count = count.min(MAX_ANTENNAS);
+    let back = match pattern {
+        SwitchingPattern::Repeat => 0,
+        SwitchingPattern::Mirror => count.saturating_sub(2),
+    };
+    let ids = (0..count).chain((1..=back).rev());
+    let mut len = 0;
+    for (slot, id) in out.iter_mut().zip(ids) {
+        *slot = id;
+        len += 1;
+    }
+    &out[..len]
+}
+
+#[derive(Clone, Copy)]
+struct Settings {
+    length_upper_bits: u8,
+    phy: Phy,
+    modulation_index: ModulationIndex,
+    cte: Option<Cte>,
+    slot_duration: SlotDuration,
+    antennas: u8,
+    pattern: SwitchingPattern,
+    tx_power: Option<TxPowerRequest>,
+}
+
+impl Settings {
+    const DEFAULT: Self = Self {
+        length_upper_bits: 0,
+        phy: Phy::Le1M,
+        modulation_index: ModulationIndex::Standard,
+        cte: None,
+        slot_duration: SlotDuration::Slot2us,
+        antennas: 1,
+        pattern: SwitchingPattern::Repeat,
+        tx_power: None,
+    };
+}
+
+/// Runs the tests requested by a tester. The controller must be initialized
+/// with [`sdc_init`](crate::sdc::sdc_init).
+pub struct Dtm {
+    settings: Settings,
+    buf: [u8; raw::HCI_MSG_BUFFER_MAX_SIZE as usize],
+}
+
+impl Default for Dtm {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Dtm {
+    pub fn new() -> Self {
+        Self {
+            settings: Settings::DEFAULT,
+            buf: [0; raw::HCI_MSG_BUFFER_MAX_SIZE as usize],
+        }
+    }
+
+    /// Run a command received from the tester, returning the response to send back.
+    pub async fn process(&mut self, cmd: u16) -> Response {
+        let Some(command) = Command::decode(cmd) else {
+            return Response::Failure;
+        };
+        match self.execute(command).await {
+            Ok(response) => response,
+            Err(e) => {
+                warn!("[dtm] command {:?} failed: {:?}", command, e);
+                Response::Failure
+            }
+        }
+    }
+
+    async fn execute(&mut self, command: Command) -> Result<Response, Error> {
+        match command {
+            Command::Setup(setup) => self.setup(setup),
+            Command::ReceiverTest { channel } => {
+                self.receiver_test(channel).await?;
+                Ok(Response::Success)
+            }
+            Command::TransmitterTest {
+                channel,
+                length,
+                payload,
+            } => {
+                let length = self.settings.length_upper_bits << 6 | length;
+                self.transmitter_test(channel, length, payload).await?;
+                Ok(Response::Success)
+            }
+            Command::TestEnd => match test_end() {
+                // No test was running.
+                Err(Error::Hci(hci::STATUS_COMMAND_DISALLOWED)) => Ok(Response::Packets(0)),
+                res => res.map(Response::Packets),
+            },
+        }
+    }
+
+    fn setup(&mut self, setup: Setup) -> Result<Response, Error> {
+        let s = &mut self.settings;
+        match setup {
+            Setup::Reset => {
+                let _ = test_end();
+                *s = Settings::DEFAULT;
+            }
+            Setup::SetLengthUpperBits(bits) => s.length_upper_bits = bits,
+            Setup::SetPhy(phy) => s.phy = phy,
+            Setup::SetModulationIndex(index) => s.modulation_index = index,
+            Setup::ReadSupportedFeatures => {
+                let mut ret =
+                    unsafe { core::mem::zeroed::<raw::sdc_hci_cmd_le_read_local_supported_features_return_t>() };
+                hci::status(unsafe { raw::sdc_hci_cmd_le_read_local_supported_features(&mut ret) })?;
+                return Ok(Response::Value(features(u64::from_le_bytes(unsafe { ret.raw }))));
+            }
+            Setup::ReadMaxValue(value) => {
+                let mut ret = unsafe { core::mem::zeroed::<raw::sdc_hci_cmd_le_read_max_data_length_return_t>() };
+                hci::status(unsafe { raw::sdc_hci_cmd_le_read_max_data_length(&mut ret) })?;
+                return Ok(Response::Value(match value {
+                    MaxValue::TxOctets => ret.supported_max_tx_octets,
+                    MaxValue::TxTime => ret.supported_max_tx_time,
+                    MaxValue::RxOctets => ret.supported_max_rx_octets,
+                    MaxValue::RxTime => ret.supported_max_rx_time,
+                }));
+            }
+            Setup::SetCte(cte) => s.cte = cte,
+            Setup::SetSlotDuration(duration) => s.slot_duration = duration,
+            Setup::SetAntennas { count, pattern } => {
+                s.antennas = count;
+                s.pattern = pattern;
+            }
+            Setup::SetTxPower(request) => {
+                // The controller picks the level when the test starts, answer
+                // with the level it will pick: the highest supported step not
+                // above the request.
+                let (min, max) = tx_power::supported_range()?;
+                let level = match request {
+                    TxPowerRequest::Min => min,
+                    TxPowerRequest::Max => max,
+                    TxPowerRequest::Dbm(dbm) => tx_power::TxPower::at_most(dbm.clamp(min, max)).dbm().max(min),
+                };
+                s.tx_power = Some(request);
+                let value = level as u8 as u16 | ((level == min) as u16) << 8 | ((level == max) as u16) << 9;
+                return Ok(Response::Value(value));
+            }
+        }
+        Ok(Response::Success)
+    }
+
+    async fn receiver_test(&mut self, channel: u8) -> Result<(), Error> {
+        let s = self.settings;
+        let mut params = [0; 7 + MAX_ANTENNAS as usize];
+        let (opcode, len) = if let Some(cte) = s.cte {
+            let mut ids = [0; MAX_ANTENNAS as usize];
+            let ids = antenna_ids(s.antennas, s.pattern, &mut ids);
+            let slot_duration = match s.slot_duration {
+                SlotDuration::Slot1us => 0x01,
+                SlotDuration::Slot2us => 0x02,
+            };
+            params[..7].copy_from_slice(&[
+                channel,
+                s.phy.rx(),
+                s.modulation_index as u8,
+                cte.length,
+                cte.kind as u8,
+                slot_duration,
+                ids.len() as u8,
+            ]);
+            params[7..7 + ids.len()].copy_from_slice(ids);
+            (OPCODE_RECEIVER_TEST_V3, 7 + ids.len())
+        } else if s.phy != Phy::Le1M || s.modulation_index != ModulationIndex::Standard {
+            params[..3].copy_from_slice(&[channel, s.phy.rx(), s.modulation_index as u8]);
+            (OPCODE_RECEIVER_TEST_V2, 3)
+        } else {
+            params[0] = channel;
+            (OPCODE_RECEIVER_TEST_V1, 1)
+        };
+        self.command(opcode, &params[..len]).await
+    }
+
+    async fn transmitter_test(&mut self, channel: u8, length: u8, payload: Payload) -> Result<(), Error> {
+        let s = self.settings;
+        let mut params = [0; 8 + MAX_ANTENNAS as usize];
+        params[..4].copy_from_slice(&[channel, length, payload.raw(), s.phy.tx()]);
+        let opcode = if s.cte.is_some() || s.tx_power.is_some() {
+            OPCODE_TRANSMITTER_TEST_V3
+        } else if s.phy != Phy::Le1M {
+            OPCODE_TRANSMITTER_TEST_V2
+        } else {
+            OPCODE_TRANSMITTER_TEST_V1
+        };
+        let mut len = match opcode {
+            OPCODE_TRANSMITTER_TEST_V1 => 3,
+            _ => 4,
+        };
+
+        if opcode == OPCODE_TRANSMITTER_TEST_V3 {
+            let mut ids = [0; MAX_ANTENNAS as usize];
+            let ids = match s.cte {
+                Some(Cte {
+                    kind: CteType::AoD1us | CteType::AoD2us,
+                    ..
+                }) => antenna_ids(s.antennas, s.pattern, &mut ids),
+                _ => &[],
+            };
+            let (length, kind) = s.cte.map_or((0, 0), |cte| (cte.length, cte.kind as u8));
+            params[4..7].copy_from_slice(&[length, kind, ids.len() as u8]);
+            params[7..7 + ids.len()].copy_from_slice(ids);
+            len = 7 + ids.len();
+        }
+
+        let opcode = match s.tx_power {
+            Some(request) => {
+                params[len] = request.raw() as u8;
+                len += 1;
+                OPCODE_TRANSMITTER_TEST_V4
+            }
+            None => opcode,
+        };
+        self.command(opcode, &params[..len]).await
+    }
+
+    /// Send a command without a dedicated function in the controller, and wait
+    /// for its Command Complete or Command Status event.
+    async fn command(&mut self, opcode: u16, params: &[u8]) -> Result<(), Error> {
+        let mut packet = [0; 3 + u8::MAX as usize];
+        packet[..2].copy_from_slice(&opcode.to_le_bytes());
+        packet[2] = params.len() as u8;
+        packet[3..3 + params.len()].copy_from_slice(params);
+        sdc::sdc_hci_write_command(&packet[..3 + params.len()])?;
+
+        loop {
+            sdc::sdc_hci_get(&mut self.buf).await?;
+            if self.buf[0] != raw::sdc_hci_msg_type_t_SDC_HCI_MSG_TYPE_EVT as u8 {
+                continue;
+            }
+            let Some(Event::Unknown { code, params }) = Event::decode(&self.buf[1..]) else {
+                continue;
+            };
+            let mut r = Reader::new(params);
+            let status = match code {
+                hci::EVT_COMMAND_COMPLETE => {
+                    let _num_hci_command_packets = r.u8();
+                    (r.u16() == Some(opcode)).then(|| r.u8())
+                }
+                hci::EVT_COMMAND_STATUS => {
+                    let status = r.u8();
+                    let _num_hci_command_packets = r.u8();
+                    (r.u16() == Some(opcode)).then_some(status)
+                }
+                _ => None,
+            };
+            if let Some(status) = status {
+                return hci::status(status.ok_or(Error::Other)?);
+            }
+        }
+    }
+}
+
+/// End the running test, returning the number of packets received.
+fn test_end() -> Result<u16, Error> {
+    let mut ret = unsafe { core::mem::zeroed::<raw::sdc_hci_cmd_le_test_end_return_t>() };
+    hci::status(unsafe { raw::sdc_hci_cmd_le_test_end(&mut ret) })?;
+    Ok(ret.num_packets)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setup(cmd: u16) -> Option<Setup> {
+        match Command::decode(cmd)? {
+            Command::Setup(setup) => Some(setup),
+            other => panic!("not a setup command: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn decode_reset() {
+        assert_eq!(setup(0x0000), Some(Setup::Reset));
+        // Don't care bits.
+        assert_eq!(setup(0x0003), Some(Setup::Reset));
+        assert_eq!(setup(0x0004), None);
+    }
+
+    #[test]
+    fn decode_length_and_phy() {
+        assert_eq!(setup(0x010C), Some(Setup::SetLengthUpperBits(3)));
+        assert_eq!(setup(0x0110), None);
+        assert_eq!(setup(0x0204), Some(Setup::SetPhy(Phy::Le1M)));
+        assert_eq!(setup(0x020B), Some(Setup::SetPhy(Phy::Le2M)));
+        assert_eq!(setup(0x020C), Some(Setup::SetPhy(Phy::LeCodedS8)));
+        assert_eq!(setup(0x0210), Some(Setup::SetPhy(Phy::LeCodedS2)));
+        assert_eq!(setup(0x0200), None);
+        assert_eq!(setup(0x0214), None);
+        assert_eq!(setup(0x0304), Some(Setup::SetModulationIndex(ModulationIndex::Stable)));
+    }
+
+    #[test]
+    fn decode_reads() {
+        assert_eq!(setup(0x0400), Some(Setup::ReadSupportedFeatures));
+        assert_eq!(setup(0x0504), Some(Setup::ReadMaxValue(MaxValue::TxTime)));
+        assert_eq!(setup(0x050C), Some(Setup::ReadMaxValue(MaxValue::RxTime)));
+        assert_eq!(setup(0x0510), None);
+    }
+
+    #[test]
+    fn decode_cte_and_antennas() {
+        assert_eq!(setup(0x0600), Some(Setup::SetCte(None)));
+        assert_eq!(
+            setup(0x0654),
+            Some(Setup::SetCte(Some(Cte {
+                length: 20,
+                kind: CteType::AoD1us
+            })))
+        );
+        assert_eq!(
+            setup(0x0682),
+            Some(Setup::SetCte(Some(Cte {
+                length: 2,
+                kind: CteType::AoD2us
+            })))
+        );
+        assert_eq!(setup(0x0601), None);
+        assert_eq!(setup(0x0615), None);
+        assert_eq!(setup(0x06C2), None);
+        assert_eq!(setup(0x0701), Some(Setup::SetSlotDuration(SlotDuration::Slot1us)));
+        assert_eq!(setup(0x0703), None);
+        assert_eq!(
+            setup(0x0884),
+            Some(Setup::SetAntennas {
+                count: 4,
+                pattern: SwitchingPattern::Mirror
+            })
+        );
+        assert_eq!(setup(0x0800), None);
+        assert_eq!(setup(0x084C), None);
+    }
+
+    #[test]
+    fn decode_tx_power() {
+        assert_eq!(setup(0x09F6), Some(Setup::SetTxPower(TxPowerRequest::Dbm(-10))));
+        assert_eq!(setup(0x0914), Some(Setup::SetTxPower(TxPowerRequest::Dbm(20))));
+        assert_eq!(setup(0x097E), Some(Setup::SetTxPower(TxPowerRequest::Min)));
+        assert_eq!(setup(0x097F), Some(Setup::SetTxPower(TxPowerRequest::Max)));
+        assert_eq!(setup(0x0915), None);
+        assert_eq!(setup(0x0980), None);
+    }
+
+    #[test]
+    fn decode_unknown_control() {
+        assert_eq!(setup(0x0A00), None);
+        assert_eq!(setup(0x3F00), None);
+    }
+
+    #[test]
+    fn decode_tests() {
+        assert_eq!(
+            Command::from_bytes([0x93, 0x96]),
+            Some(Command::TransmitterTest {
+                channel: 19,
+                length: 37,
+                payload: Payload::Pattern10101010,
+            })
+        );
+        assert_eq!(
+            Command::decode(0x8003),
+            Some(Command::TransmitterTest {
+                channel: 0,
+                length: 0,
+                payload: Payload::AllOnes,
+            })
+        );
+        assert_eq!(Command::decode(0xA800), None);
+        assert_eq!(Command::decode(0x67FF), Some(Command::ReceiverTest { channel: 39 }));
+        assert_eq!(Command::decode(0x6800), None);
+        assert_eq!(Command::decode(0xC000), Some(Command::TestEnd));
+    }
+
+    #[test]
+    fn encode_responses() {
+        assert_eq!(Response::Success.to_bytes(), [0x00, 0x00]);
+        assert_eq!(Response::Failure.to_bytes(), [0x00, 0x01]);
+        assert_eq!(Response::Value(251).to_bytes(), [0x01, 0xF6]);
+        assert_eq!(Response::Packets(0x0123).to_bytes(), [0x81, 0x23]);
+        // The packet counter is 15 bits.
+        assert_eq!(Response::Packets(0x8001).encode(), 0x8001);
+    }
+
+    #[test]
+    fn feature_bits() {
+        assert_eq!(features(0), 0);
+        assert_eq!(
+            features(LE_FEATURE_DATA_LENGTH_EXTENSION | LE_FEATURE_2M_PHY | LE_FEATURE_CODED_PHY),
+            0b1011
+        );
+        assert_eq!(features(LE_FEATURE_RECEIVING_CTE), DTM_FEATURE_CTE);
+    }
+
+    #[test]
+    fn switching_patterns() {
+        let mut out = [0; MAX_ANTENNAS as usize];
+        assert_eq!(antenna_ids(3, SwitchingPattern::Repeat, &mut out), &[0, 1, 2]);
+        assert_eq!(antenna_ids(4, SwitchingPattern::Mirror, &mut out), &[0, 1, 2, 3, 2, 1]);
+        assert_eq!(antenna_ids(1, SwitchingPattern::Mirror, &mut out), &[0]);
+        let ids = antenna_ids(MAX_ANTENNAS, SwitchingPattern::Mirror, &mut out);
+        assert_eq!(ids.len(), MAX_ANTENNAS as usize);
+        assert_eq!(ids[MAX_ANTENNAS as usize - 1], MAX_ANTENNAS - 1);
+    }
+}
@@ -0,0 +1,665 @@
+//! HCI types shared by the high level APIs, and decoding of the events
+//! received from the controller.
+use super::raw;
+use super::Error;
+use core::cell::Cell;
+use embassy_sync::blocking_mutex::CriticalSectionMutex;
+
+pub(crate) const STATUS_SUCCESS: u8 = 0x00;
+#[cfg(any(feature = "central", feature = "multirole"))]
+pub(crate) const STATUS_UNKNOWN_CONN_ID: u8 = 0x02;
+pub(crate) const STATUS_COMMAND_DISALLOWED: u8 = 0x0C;
+pub(crate) const STATUS_REMOTE_USER_TERMINATED: u8 = 0x13;
+#[cfg(any(feature = "peripheral", feature = "multirole"))]
+pub(crate) const STATUS_ADV_TIMEOUT: u8 = 0x3C;
+#[cfg(any(feature = "peripheral", feature = "multirole"))]
+pub(crate) const STATUS_LIMIT_REACHED: u8 = 0x43;
+#[cfg(feature = "multirole")]
+pub(crate) const STATUS_OPERATION_CANCELLED: u8 = 0x44;
+
+pub(crate) const EVT_DISCONNECTION_COMPLETE: u8 = 0x05;
+pub(crate) const EVT_ENCRYPTION_CHANGE: u8 = 0x08;
+pub(crate) const EVT_COMMAND_COMPLETE: u8 = 0x0E;
+pub(crate) const EVT_COMMAND_STATUS: u8 = 0x0F;
+pub(crate) const EVT_ENCRYPTION_KEY_REFRESH_COMPLETE: u8 = 0x30;
+pub(crate) const EVT_LE_META: u8 = 0x3E;
+pub(crate) const EVT_ENCRYPTION_CHANGE_V2: u8 = 0x59;
+pub(crate) const EVT_VENDOR: u8 = 0xFF;
+
+pub(crate) const SUBEVT_CONN_COMPLETE: u8 = 0x01;
+pub(crate) const SUBEVT_CONN_UPDATE_COMPLETE: u8 = 0x03;
+pub(crate) const SUBEVT_READ_REMOTE_FEATURES_COMPLETE: u8 = 0x04;
+pub(crate) const SUBEVT_LTK_REQUEST: u8 = 0x05;
+pub(crate) const SUBEVT_DATA_LENGTH_CHANGE: u8 = 0x07;
+pub(crate) const SUBEVT_ENHANCED_CONN_COMPLETE: u8 = 0x0A;
+pub(crate) const SUBEVT_PHY_UPDATE_COMPLETE: u8 = 0x0C;
+pub(crate) const SUBEVT_EXT_ADV_REPORT: u8 = 0x0D;
+pub(crate) const SUBEVT_PERIODIC_SYNC_ESTABLISHED: u8 = 0x0E;
+pub(crate) const SUBEVT_PERIODIC_ADV_REPORT: u8 = 0x0F;
+pub(crate) const SUBEVT_PERIODIC_SYNC_LOST: u8 = 0x10;
+pub(crate) const SUBEVT_ADV_SET_TERMINATED: u8 = 0x12;
+pub(crate) const SUBEVT_PERIODIC_SYNC_TRANSFER_RECEIVED: u8 = 0x18;
+pub(crate) const SUBEVT_PATH_LOSS_THRESHOLD: u8 = 0x20;
+pub(crate) const SUBEVT_TRANSMIT_POWER_REPORTING: u8 = 0x21;
+
+pub(crate) const SUBEVT_VS_QOS_CONN_EVENT_REPORT: u8 = 0x80;
+
+/// Convert the status code returned by an `sdc_hci_cmd_*` function.
+pub(crate) fn status(ret: u8) -> Result<(), Error> {
+    match ret {
+        STATUS_SUCCESS => Ok(()),
+        code => Err(Error::Hci(code)),
+    }
+}
+
+// Subevents 0x01 to 0x05 are enabled after reset.
+static LE_EVENT_MASK: CriticalSectionMutex<Cell<u64>> = CriticalSectionMutex::new(Cell::new(0x1F));
+
+/// Unmask the given LE meta subevents in the controller.
+pub(crate) fn enable_le_events(subevents: &[u8]) -> Result<(), Error> {
+    let mask = LE_EVENT_MASK.lock(|mask| {
+        let mut value = mask.get();
+        for subevent in subevents {
+            value |= 1 << (subevent - 1);
+        }
+        mask.set(value);
+        value
+    });
+    let params = raw::sdc_hci_cmd_le_set_event_mask_t {
+        raw: mask.to_le_bytes(),
+    };
+    status(unsafe { raw::sdc_hci_cmd_le_set_event_mask(&params) })
+}
+
+// All events on page 2 are masked after reset.
+static EVENT_MASK_PAGE_2: CriticalSectionMutex<Cell<u64>> = CriticalSectionMutex::new(Cell::new(0));
+
+/// Unmask the given bits of the page 2 event mask in the controller.
+pub(crate) fn enable_events_page_2(bits: &[u8]) -> Result<(), Error> {
+    let mask = EVENT_MASK_PAGE_2.lock(|mask| {
+        let mut value = mask.get();
+        for bit in bits {
+            value |= 1 << bit;
+        }
+        mask.set(value);
+        value
+    });
+    let params = raw::sdc_hci_cmd_cb_set_event_mask_page_2_t {
+        raw: mask.to_le_bytes(),
+    };
+    status(unsafe { raw::sdc_hci_cmd_cb_set_event_mask_page_2(&params) })
+}
+
+/// Page 2 event mask bit of the Encryption Change event carrying the key size.
+pub(crate) const EVT_MASK_PAGE_2_ENCRYPTION_CHANGE_V2: u8 = 25;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Phy {
+    Le1M = 1,
+    Le2M = 2,
+    LeCoded = 3,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum AddrKind {
+    Public = 0,
+    Random = 1,
+    /// Resolvable private address, falling back to the public address.
+    ResolvablePublic = 2,
+    /// Resolvable private address, falling back to the random address.
+    ResolvableRandom = 3,
+}
+
+impl AddrKind {
+    pub(crate) fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(Self::Public),
+            1 => Some(Self::Random),
+            2 => Some(Self::ResolvablePublic),
+            3 => Some(Self::ResolvableRandom),
+            _ => None,
+        }
+    }
+}
+
+impl Phy {
+    pub(crate) fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            1 => Some(Self::Le1M),
+            2 => Some(Self::Le2M),
+            3 => Some(Self::LeCoded),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Address {
+    pub kind: AddrKind,
+    /// Address bytes in little endian order, as used on the HCI.
+    pub addr: [u8; 6],
+}
+
+/// An HCI event received from the controller.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Event<'a> {
+    DisconnectionComplete(DisconnectionComplete),
+    /// Either an Encryption Change event or its second version.
+    EncryptionChange(EncryptionChange),
+    EncryptionKeyRefreshComplete(EncryptionKeyRefreshComplete),
+    Le(LeEvent<'a>),
+    Vendor(VendorEvent<'a>),
+    /// An event not decoded by this crate.
+    Unknown {
+        code: u8,
+        params: &'a [u8],
+    },
+}
+
+/// Subevents of the LE Meta event.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum LeEvent<'a> {
+    /// Either an LE Connection Complete or LE Enhanced Connection Complete event.
+    ConnectionComplete(ConnectionComplete),
+    ConnectionUpdateComplete(ConnectionUpdateComplete),
+    ReadRemoteFeaturesComplete(ReadRemoteFeaturesComplete),
+    LongTermKeyRequest(LongTermKeyRequest),
+    DataLengthChange(DataLengthChange),
+    PhyUpdateComplete(PhyUpdateComplete),
+    ExtAdvReport(ExtAdvReports<'a>),
+    PeriodicSyncEstablished(PeriodicSyncEstablished),
+    PeriodicAdvReport(PeriodicAdvReport<'a>),
+    PeriodicSyncLost {
+        sync_handle: u16,
+    },
+    AdvSetTerminated(AdvSetTerminated),
+    PeriodicSyncTransferReceived(PeriodicSyncTransferReceived),
+    PathLossThreshold {
+        conn_handle: u16,
+        path_loss: u8,
+        zone: u8,
+    },
+    TransmitPowerReporting(TransmitPowerReporting),
+    /// A subevent not decoded by this crate.
+    Unknown {
+        subevent: u8,
+        params: &'a [u8],
+    },
+}
+
+/// Vendor specific events of the SoftDevice Controller.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum VendorEvent<'a> {
+    QosConnEventReport(QosConnEventReport),
+    /// A subevent not decoded by this crate.
+    Unknown {
+        subevent: u8,
+        params: &'a [u8],
+    },
+}
+
+/// Quality of a single connection event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct QosConnEventReport {
+    pub conn_handle: u16,
+    pub event_counter: u16,
+    /// Data channel index used, 0 to 36.
+    pub channel_index: u8,
+    /// Packets received with a good CRC.
+    pub crc_ok_count: u16,
+    /// Packets received with a bad CRC.
+    pub crc_error_count: u16,
+    /// Negative acknowledgements received from the peer.
+    pub nak_count: u16,
+    /// The event ended because nothing was received in time.
+    pub rx_timeout: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct PeriodicSyncEstablished {
+    pub status: u8,
+    pub sync_handle: u16,
+    pub sid: u8,
+    pub addr_kind: u8,
+    pub addr: [u8; 6],
+    pub phy: u8,
+    pub interval: u16,
+    pub clock_accuracy: u8,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct PeriodicSyncTransferReceived {
+    pub status: u8,
+    pub conn_handle: u16,
+    pub service_data: u16,
+    pub sync_handle: u16,
+    pub sid: u8,
+    pub addr_kind: u8,
+    pub addr: [u8; 6],
+    pub phy: u8,
+    pub interval: u16,
+    pub clock_accuracy: u8,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct TransmitPowerReporting {
+    pub status: u8,
+    pub conn_handle: u16,
+    pub reason: u8,
+    pub phy: u8,
+    pub tx_power: i8,
+    pub flags: u8,
+    pub delta: i8,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct PeriodicAdvReport<'a> {
+    pub sync_handle: u16,
+    pub tx_power: i8,
+    pub rssi: i8,
+    pub cte_type: u8,
+    pub data_status: u8,
+    pub data: &'a [u8],
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AdvSetTerminated {
+    pub status: u8,
+    pub adv_handle: u8,
+    pub conn_handle: u16,
+    pub num_completed_events: u8,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct DisconnectionComplete {
+    pub status: u8,
+    pub handle: u16,
+    pub reason: u8,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct EncryptionChange {
+    pub status: u8,
+    pub handle: u16,
+    pub enabled: u8,
+    /// Only reported by the second version of the event.
+    pub key_size: Option<u8>,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct EncryptionKeyRefreshComplete {
+    pub status: u8,
+    pub handle: u16,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct LongTermKeyRequest {
+    pub handle: u16,
+    pub rand: [u8; 8],
+    pub ediv: u16,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ConnectionComplete {
+    pub status: u8,
+    pub handle: u16,
+    pub role: u8,
+    pub peer_addr_kind: u8,
+    pub peer_addr: [u8; 6],
+    pub interval: u16,
+    pub latency: u16,
+    pub supervision_timeout: u16,
+    pub clock_accuracy: u8,
+}
+
+impl ConnectionComplete {
+    fn decode(r: &mut Reader, enhanced: bool) -> Option<Self> {
+        let status = r.u8()?;
+        let handle = r.u16()?;
+        let role = r.u8()?;
+        let peer_addr_kind = r.u8()?;
+        let peer_addr = r.array()?;
+        if enhanced {
+            // Local and peer resolvable private addresses.
+            r.bytes(12)?;
+        }
+        Some(Self {
+            status,
+            handle,
+            role,
+            peer_addr_kind,
+            peer_addr,
+            interval: r.u16()?,
+            latency: r.u16()?,
+            supervision_timeout: r.u16()?,
+            clock_accuracy: r.u8()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ConnectionUpdateComplete {
+    pub status: u8,
+    pub handle: u16,
+    pub interval: u16,
+    pub latency: u16,
+    pub supervision_timeout: u16,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ReadRemoteFeaturesComplete {
+    pub status: u8,
+    pub handle: u16,
+    pub features: [u8; 8],
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct DataLengthChange {
+    pub handle: u16,
+    pub max_tx_octets: u16,
+    pub max_tx_time: u16,
+    pub max_rx_octets: u16,
+    pub max_rx_time: u16,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct PhyUpdateComplete {
+    pub status: u8,
+    pub handle: u16,
+    pub tx_phy: u8,
+    pub rx_phy: u8,
+}
+
+/// The reports carried by an LE Extended Advertising Report event.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ExtAdvReports<'a> {
+    count: u8,
+    params: &'a [u8],
+}
+
+impl<'a> ExtAdvReports<'a> {
+    pub fn iter(&self) -> impl Iterator<Item = ExtAdvReport<'a>> {
+        let mut r = Reader::new(self.params);
+        (0..self.count).map_while(move |_| ExtAdvReport::decode(&mut r))
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ExtAdvReport<'a> {
+    pub event_type: u16,
+    pub addr_kind: u8,
+    pub addr: [u8; 6],
+    pub primary_phy: u8,
+    pub secondary_phy: u8,
+    pub sid: u8,
+    pub tx_power: i8,
+    pub rssi: i8,
+    pub periodic_adv_interval: u16,
+    pub direct_addr_kind: u8,
+    pub direct_addr: [u8; 6],
+    pub data: &'a [u8],
+}
+
+impl<'a> ExtAdvReport<'a> {
+    fn decode(r: &mut Reader<'a>) -> Option<Self> {
+        Some(Self {
+            event_type: r.u16()?,
+            addr_kind: r.u8()?,
+            addr: r.array()?,
+            primary_phy: r.u8()?,
+            secondary_phy: r.u8()?,
+            sid: r.u8()?,
+            tx_power: r.i8()?,
+            rssi: r.i8()?,
+            periodic_adv_interval: r.u16()?,
+            direct_addr_kind: r.u8()?,
+            direct_addr: r.array()?,
+            data: {
+                let len = r.u8()? as usize;
+                r.bytes(len)?
+            },
+        })
+    }
+}
+
+impl<'a> Event<'a> {
+    /// Decode an event packet, starting with the event code.
+    pub fn decode(packet: &'a [u8]) -> Option<Self> {
+        let mut r = Reader::new(packet);
+        let code = r.u8()?;
+        let len = r.u8()? as usize;
+        let mut r = Reader::new(r.bytes(len)?);
+        let event = match code {
+            EVT_DISCONNECTION_COMPLETE => Self::DisconnectionComplete(DisconnectionComplete {
+                status: r.u8()?,
+                handle: r.u16()?,
+                reason: r.u8()?,
+            }),
+            EVT_ENCRYPTION_CHANGE | EVT_ENCRYPTION_CHANGE_V2 => Self::EncryptionChange(EncryptionChange {
+                status: r.u8()?,
+                handle: r.u16()?,
+                enabled: r.u8()?,
+                key_size: match code {
+                    EVT_ENCRYPTION_CHANGE_V2 => Some(r.u8()?),
+                    _ => None,
+                },
+            }),
+            EVT_ENCRYPTION_KEY_REFRESH_COMPLETE => Self::EncryptionKeyRefreshComplete(EncryptionKeyRefreshComplete {
+                status: r.u8()?,
+                handle: r.u16()?,
+            }),
+            EVT_LE_META => Self::Le(LeEvent::decode(&mut r)?),
+            EVT_VENDOR => Self::Vendor(VendorEvent::decode(&mut r)?),
+            _ => Self::Unknown { code, params: r.rest() },
+        };
+        Some(event)
+    }
+}
+
+impl<'a> LeEvent<'a> {
+    fn decode(r: &mut Reader<'a>) -> Option<Self> {
+        let subevent = r.u8()?;
+        let event = match subevent {
+            SUBEVT_CONN_COMPLETE => Self::ConnectionComplete(ConnectionComplete::decode(r, false)?),
+            SUBEVT_ENHANCED_CONN_COMPLETE => Self::ConnectionComplete(ConnectionComplete::decode(r, true)?),
+            SUBEVT_CONN_UPDATE_COMPLETE => Self::ConnectionUpdateComplete(ConnectionUpdateComplete {
+                status: r.u8()?,
+                handle: r.u16()?,
+                interval: r.u16()?,
+                latency: r.u16()?,
+                supervision_timeout: r.u16()?,
+            }),
+            SUBEVT_READ_REMOTE_FEATURES_COMPLETE => Self::ReadRemoteFeaturesComplete(ReadRemoteFeaturesComplete {
+                status: r.u8()?,
+                handle: r.u16()?,
+                features: r.array()?,
+            }),
+            SUBEVT_LTK_REQUEST => Self::LongTermKeyRequest(LongTermKeyRequest {
+                handle: r.u16()?,
+                rand: r.array()?,
+                ediv: r.u16()?,
+            }),
+            SUBEVT_DATA_LENGTH_CHANGE => Self::DataLengthChange(DataLengthChange {
+                handle: r.u16()?,
+                max_tx_octets: r.u16()?,
+                max_tx_time: r.u16()?,
+                max_rx_octets: r.u16()?,
+                max_rx_time: r.u16()?,
+            }),
+            SUBEVT_PHY_UPDATE_COMPLETE => Self::PhyUpdateComplete(PhyUpdateComplete {
+                status: r.u8()?,
+                handle: r.u16()?,
+                tx_phy: r.u8()?,
+                rx_phy: r.u8()?,
+            }),
+            SUBEVT_EXT_ADV_REPORT => Self::ExtAdvReport(ExtAdvReports {
+                count: r.u8()?,
+                params: r.rest(),
+            }),
+            SUBEVT_PERIODIC_SYNC_ESTABLISHED => Self::PeriodicSyncEstablished(PeriodicSyncEstablished {
+                status: r.u8()?,
+                sync_handle: r.u16()?,
+                sid: r.u8()?,
+                addr_kind: r.u8()?,
+                addr: r.array()?,
+                phy: r.u8()?,
+                interval: r.u16()?,
+                clock_accuracy: r.u8()?,
+            }),
+            SUBEVT_PERIODIC_ADV_REPORT => Self::PeriodicAdvReport(PeriodicAdvReport {
+                sync_handle: r.u16()?,
+                tx_power: r.i8()?,
+                rssi: r.i8()?,
+                cte_type: r.u8()?,
+                data_status: r.u8()?,
+                data: {
+                    let len = r.u8()? as usize;
+                    r.bytes(len)?
+                },
+            }),
+            SUBEVT_PERIODIC_SYNC_LOST => Self::PeriodicSyncLost { sync_handle: r.u16()? },
+            SUBEVT_PERIODIC_SYNC_TRANSFER_RECEIVED => {
+                Self::PeriodicSyncTransferReceived(PeriodicSyncTransferReceived {
+                    status: r.u8()?,
+                    conn_handle: r.u16()?,
+                    service_data: r.u16()?,
+                    sync_handle: r.u16()?,
+                    sid: r.u8()?,
+                    addr_kind: r.u8()?,
+                    addr: r.array()?,
+                    phy: r.u8()?,
+                    interval: r.u16()?,
+                    clock_accuracy: r.u8()?,
+                })
+            }
+            SUBEVT_PATH_LOSS_THRESHOLD => Self::PathLossThreshold {
+                conn_handle: r.u16()?,
+                path_loss: r.u8()?,
+                zone: r.u8()?,
+            },
+            SUBEVT_TRANSMIT_POWER_REPORTING => Self::TransmitPowerReporting(TransmitPowerReporting {
+                status: r.u8()?,
+                conn_handle: r.u16()?,
+                reason: r.u8()?,
+                phy: r.u8()?,
+                tx_power: r.i8()?,
+                flags: r.u8()?,
+                delta: r.i8()?,
+            }),
+            SUBEVT_ADV_SET_TERMINATED => Self::AdvSetTerminated(AdvSetTerminated {
+                status: r.u8()?,
+                adv_handle: r.u8()?,
+                conn_handle: r.u16()?,
+                num_completed_events: r.u8()?,
+            }),
+            _ => Self::Unknown {
+                subevent,
+                params: r.rest(),
+            },
+        };
+        Some(event)
+    }
+}
+
+impl<'a> VendorEvent<'a> {
+    fn decode(r: &mut Reader<'a>) -> Option<Self> {
+        let subevent = r.u8()?;
+        let event = match subevent {
+            SUBEVT_VS_QOS_CONN_EVENT_REPORT => Self::QosConnEventReport(QosConnEventReport {
+                conn_handle: r.u16()?,
+                event_counter: r.u16()?,
+                channel_index: r.u8()?,
+                crc_ok_count: r.u16()?,
+                crc_error_count: r.u16()?,
+                nak_count: r.u16()?,
+                rx_timeout: r.u8()? & 0x01 != 0,
+            }),
+            _ => Self::Unknown {
+                subevent,
+                params: r.rest(),
+            },
+        };
+        Some(event)
+    }
+}
+
+/// Hand an event to every high level API that may be waiting for it.
+pub(crate) fn dispatch(event: &Event) {
+    // Connections are tracked first, so the other APIs can look them up.
+    crate::conn::on_event(event);
+    #[cfg(any(feature = "central", feature = "multirole"))]
+    crate::central::on_event(event);
+    #[cfg(any(feature = "peripheral", feature = "multirole"))]
+    crate::security::on_event(event);
+    crate::power::on_event(event);
+    crate::qos::on_event(event);
+    #[cfg(any(feature = "peripheral", feature = "multirole"))]
+    crate::adv::on_event(event);
+    #[cfg(any(feature = "central", feature = "multirole"))]
+    crate::scan::on_event(event);
+    #[cfg(feature = "multirole")]
+    crate::periodic::on_event(event);
+}
+
+/// Little endian reader over event parameters.
+pub(crate) struct Reader<'a> {
+    buf: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    pub(crate) fn new(buf: &'a [u8]) -> Self {
+        Self { buf }
+    }
+
+    pub(crate) fn bytes(&mut self, len: usize) -> Option<&'a [u8]> {
+        if self.buf.len() < len {
+            return None;
+        }
+        let (head, tail) = self.buf.split_at(len);
+        self.buf = tail;
+        Some(head)
+    }
+
+    pub(crate) fn array<const N: usize>(&mut self) -> Option<[u8; N]> {
+        self.bytes(N)?.try_into().ok()
+    }
+
+    pub(crate) fn u8(&mut self) -> Option<u8> {
+        Some(self.array::<1>()?[0])
+    }
+
+    pub(crate) fn i8(&mut self) -> Option<i8> {
+        Some(self.u8()? as i8)
+    }
+
+    pub(crate) fn u16(&mut self) -> Option<u16> {
+        Some(u16::from_le_bytes(self.array()?))
+    }
+
+    pub(crate) fn rest(&mut self) -> &'a [u8] {
+        core::mem::take(&mut self.buf)
+    }
+}
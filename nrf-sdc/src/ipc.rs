@@ -0,0 +1,376 @@
+//! HCI transport to the application core of the nRF5340, for running the
+//! controller on the network core with the host on the application core.
+//!
+//! Packets are exchanged in H4 format, a packet type byte followed by the HCI
+//! packet, through two rings in RAM shared by both cores. The application
+//! core places a zeroed [`Shared`] in its RAM before starting the network
+//! core, and both cores agree on its address. Each ring is laid out as:
+//!
+//! | Offset | Size          | Field                                               |
+//! |--------|---------------|-----------------------------------------------------|
+//! | 0      | 4             | `head`, bytes written, only advanced by the writer |
+//! | 4      | 4             | `tail`, bytes read, only advanced by the reader    |
+//! | 8      | [`RING_SIZE`] | data                                                |
+//!
+//! `head` and `tail` wrap around at `u32::MAX` and index the data modulo
+//! [`RING_SIZE`]. Every packet is preceded by its length as a little endian
+//! `u16`, and may wrap around the end of the data.
+//!
+//! The network core triggers IPC channel [`CH_TO_APP`] after writing packets
+//! or reading them, and the application core triggers [`CH_TO_NET`] likewise.
+//!
+//! Events can only be received once, so this replaces [`sdc_run`](crate::sdc::sdc_run).
+use super::raw;
+use super::sdc;
+use core::cell::UnsafeCell;
+use core::future::poll_fn;
+use core::sync::atomic::{AtomicU32, Ordering};
+use core::task::Poll;
+use embassy_futures::select::select;
+use embassy_nrf::interrupt::typelevel::{Binding, Handler, Interrupt};
+use embassy_nrf::{interrupt, pac};
+use embassy_sync::waitqueue::AtomicWaker;
+
+/// Size of the data of each ring in bytes, a power of two.
+pub const RING_SIZE: usize = 2048;
+
+/// IPC channel signalling the application core.
+pub const CH_TO_APP: usize = 0;
+/// IPC channel signalling the network core.
+pub const CH_TO_NET: usize = 1;
+
+const H4_CMD: u8 = 0x01;
+const H4_ACL: u8 = 0x02;
+const H4_EVT: u8 = 0x04;
+const H4_ISO: u8 = 0x05;
+
+const LEN_SIZE: usize = 2;
+
+/// Packets in one direction.
+#[repr(C)]
+pub struct Ring {
+    head: AtomicU32,
+    tail: AtomicU32,
+    data: UnsafeCell<[u8; RING_SIZE]>,
+}
+
+// Only the writer touches the data between `tail` and `head + RING_SIZE`, and
+// only the reader the data between `tail` and `head`.
+unsafe impl Sync for Ring {}
+
+impl Ring {
+    pub const fn new() -> Self {
+        Self {
+            head: AtomicU32::new(0),
+            tail: AtomicU32::new(0),
+            data: UnsafeCell::new([0; RING_SIZE]),
+        }
+    }
+
+    /// Append `packet`, or `false` if there is no room for it right now.
+    fn push(&self, packet: &[u8]) -> bool {
+        let head = self.head.load(Ordering::Relaxed);
+        let tail = self.tail.load(Ordering::Acquire);
+        let used = head.wrapping_sub(tail) as usize;
+        if RING_SIZE - used < LEN_SIZE + packet.len() {
+            return false;
+        }
+        self.copy_in(head, &(packet.len() as u16).to_le_bytes());
+        self.copy_in(head.wrapping_add(LEN_SIZE as u32), packet);
+        self.head
+            .store(head.wrapping_add((LEN_SIZE + packet.len()) as u32), Ordering::Release);
+        true
+    }
+
+    /// Take the next packet into `buf` and return its length. Packets longer
+    /// than `buf` are dropped, and so is everything written so far if the
+    /// ring does not hold a valid packet.
+    fn pop(&self, buf: &mut [u8]) -> Option<usize> {
+        loop {
+            let tail = self.tail.load(Ordering::Relaxed);
+            let head = self.head.load(Ordering::Acquire);
+            if head == tail {
+                return None;
+            }
+            let used = head.wrapping_sub(tail) as usize;
+            let mut len = [0; LEN_SIZE];
+            self.copy_out(tail, &mut len);
+            let len = u16::from_le_bytes(len) as usize;
+            // The writer publishes whole packets, so anything else is corruption.
+            if !(LEN_SIZE..=RING_SIZE).contains(&used) || len > used - LEN_SIZE {
+                warn!("[ipc] corrupt ring, dropping {} bytes", used);
+                self.tail.store(head, Ordering::Release);
+                return None;
+            }
+            let fits = len <= buf.len();
+            if fits {
+                self.copy_out(tail.wrapping_add(LEN_SIZE as u32), &mut buf[..len]);
+            }
+            self.tail
+                .store(tail.wrapping_add((LEN_SIZE + len) as u32), Ordering::Release);
+            if fits {
+                return Some(len);
+            }
+            warn!("[ipc] dropping packet of {} bytes", len);
+        }
+    }
+
+    fn copy_in(&self, offset: u32, src: &[u8]) {
+        let data = self.data.get() as *mut u8;
+        for (i, b) in src.iter().enumerate() {
+            let index = offset.wrapping_add(i as u32) as usize % RING_SIZE;
+            unsafe { data.add(index).write_volatile(*b) };
+        }
+    }
+
+    fn copy_out(&self, offset: u32, dst: &mut [u8]) {
+        let data = self.data.get() as *const u8;
+        for (i, b) in dst.iter_mut().enumerate() {
+            let index = offset.wrapping_add(i as u32) as usize % RING_SIZE;
+            *b = unsafe { data.add(index).read_volatile() };
+        }
+    }
+}
+
+impl Default for Ring {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The RAM shared with the application core.
+#[repr(C)]
+#[derive(Default)]
+pub struct Shared {
+    /// Packets from the application core to the controller.
+    pub to_net: Ring,
+    /// Packets from the controller to the application core.
+    pub to_app: Ring,
+}
+
+impl Shared {
+    pub const fn new() -> Self {
+        Self {
+            to_net: Ring::new(),
+            to_app: Ring::new(),
+        }
+    }
+}
+
+/// Length of the H4 packet in `buf`, from its header.
+fn packet_len(buf: &[u8]) -> Option<usize> {
+    let len = match *buf.first()? {
+        H4_EVT => 3 + *buf.get(2)? as usize,
+        H4_ACL => 5 + u16::from_le_bytes([*buf.get(3)?, *buf.get(4)?]) as usize,
+        H4_ISO => 5 + (u16::from_le_bytes([*buf.get(3)?, *buf.get(4)?]) & 0x3FFF) as usize,
+        _ => return None,
+    };
+    (len <= buf.len()).then_some(len)
+}
+
+static TO_NET_WAKER: AtomicWaker = AtomicWaker::new();
+static TO_APP_WAKER: AtomicWaker = AtomicWaker::new();
+
+fn regs() -> &'static pac::ipc::RegisterBlock {
+    unsafe { &*pac::IPC::ptr() }
+}
+
+fn signal_app() {
+    regs().tasks_send[CH_TO_APP].write(|w| unsafe { w.bits(1) });
+}
+
+/// Wakes the bridge when the application core triggers [`CH_TO_NET`].
+pub struct InterruptHandler;
+impl Handler<interrupt::typelevel::IPC> for InterruptHandler {
+    unsafe fn on_interrupt() {
+        let r = regs();
+        if r.events_receive[CH_TO_NET].read().bits() != 0 {
+            r.events_receive[CH_TO_NET].write(|w| w.bits(0));
+            // New packets, or room for more.
+            TO_NET_WAKER.wake();
+            TO_APP_WAKER.wake();
+        }
+    }
+}
+
+/// Forwards HCI packets between the controller and the application core.
+pub struct HciBridge<'d> {
+    shared: &'d Shared,
+    _ipc: pac::IPC,
+}
+
+impl<'d> HciBridge<'d> {
+    /// `ipc` comes from the PAC, as embassy-nrf has no IPC peripheral for the
+    /// network core.
+    pub fn new(
+        ipc: pac::IPC,
+        _irq: impl Binding<interrupt::typelevel::IPC, InterruptHandler> + 'd,
+        shared: &'d Shared,
+    ) -> Self {
+        let r = regs();
+        r.send_cnf[CH_TO_APP].write(|w| unsafe { w.bits(1 << CH_TO_APP) });
+        r.receive_cnf[CH_TO_NET].write(|w| unsafe { w.bits(1 << CH_TO_NET) });
+        r.intenset.write(|w| unsafe { w.bits(1 << CH_TO_NET) });
+        interrupt::typelevel::IPC::unpend();
+        unsafe { interrupt::typelevel::IPC::enable() };
+        Self { shared, _ipc: ipc }
+    }
+
+    /// Forward packets in both directions. Never returns.
+    pub async fn run(&mut self) {
+        select(self.to_app(), self.to_net()).await;
+    }
+
+    async fn to_app(&self) {
+        let mut buf = [0; raw::HCI_MSG_BUFFER_MAX_SIZE as usize];
+        loop {
+            if let Err(e) = sdc::sdc_hci_get(&mut buf).await {
+                warn!("[ipc] hci get failed: {:?}", e);
+                continue;
+            }
+            let Some(len) = packet_len(&buf) else {
+                warn!("[ipc] malformed packet from the controller");
+                continue;
+            };
+            poll_fn(|cx| {
+                TO_APP_WAKER.register(cx.waker());
+                match self.shared.to_app.push(&buf[..len]) {
+                    true => Poll::Ready(()),
+                    false => Poll::Pending,
+                }
+            })
+            .await;
+            signal_app();
+        }
+    }
+
+    async fn to_net(&self) {
+        let mut buf = [0; raw::HCI_MSG_BUFFER_MAX_SIZE as usize];
+        loop {
+            let len = poll_fn(|cx| {
+                TO_NET_WAKER.register(cx.waker());
+                match self.shared.to_net.pop(&mut buf) {
+                    Some(len) => Poll::Ready(len),
+                    None => Poll::Pending,
+                }
+            })
+            .await;
+            // Room for more packets.
+            signal_app();
+
+            let res = match buf[..len].split_first() {
+                Some((&H4_CMD, packet)) => sdc::sdc_hci_write_command(packet),
+                Some((&H4_ACL, packet)) => sdc::sdc_hci_write_data(packet),
+                Some((kind, _)) => {
+                    warn!("[ipc] unsupported packet type {}", kind);
+                    continue;
+                }
+                None => continue,
+            };
+            if let Err(e) = res {
+                warn!("[ipc] hci write failed: {:?}", e);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn packet(seed: u8, len: usize) -> Vec<u8> {
+        (0..len).map(|i| seed.wrapping_add(i as u8)).collect()
+    }
+
+    #[test]
+    fn push_and_pop() {
+        let ring = Ring::new();
+        let mut buf = [0; 300];
+        assert_eq!(ring.pop(&mut buf), None);
+        assert!(ring.push(&packet(1, 10)));
+        assert!(ring.push(&[]));
+        assert_eq!(ring.pop(&mut buf), Some(10));
+        assert_eq!(buf[..10], packet(1, 10));
+        assert_eq!(ring.pop(&mut buf), Some(0));
+        assert_eq!(ring.pop(&mut buf), None);
+    }
+
+    #[test]
+    fn refuses_packets_without_room() {
+        let ring = Ring::new();
+        assert!(!ring.push(&packet(0, RING_SIZE - LEN_SIZE + 1)));
+        assert!(ring.push(&packet(0, RING_SIZE - LEN_SIZE)));
+        assert!(!ring.push(&[]));
+        let mut buf = [0; RING_SIZE];
+        assert_eq!(ring.pop(&mut buf), Some(RING_SIZE - LEN_SIZE));
+        assert!(ring.push(&[]));
+    }
+
+    #[test]
+    fn wraps_around_the_buffer() {
+        let ring = Ring::new();
+        let mut buf = [0; 300];
+        // Packet sizes that do not divide the ring, so that lengths and data
+        // are split at its end.
+        for i in 0..100u8 {
+            let sent = packet(i, 37 + i as usize);
+            assert!(ring.push(&sent));
+            assert_eq!(ring.pop(&mut buf), Some(sent.len()));
+            assert_eq!(buf[..sent.len()], sent);
+        }
+    }
+
+    #[test]
+    fn wraps_around_the_counters() {
+        let ring = Ring::new();
+        ring.head.store(u32::MAX - 5, Ordering::Relaxed);
+        ring.tail.store(u32::MAX - 5, Ordering::Relaxed);
+        let mut buf = [0; 300];
+        for i in 0..4 {
+            assert!(ring.push(&packet(i, 100)));
+        }
+        for i in 0..4 {
+            assert_eq!(ring.pop(&mut buf), Some(100));
+            assert_eq!(buf[..100], packet(i, 100));
+        }
+        assert_eq!(ring.pop(&mut buf), None);
+    }
+
+    #[test]
+    fn drops_packets_longer_than_the_buffer() {
+        let ring = Ring::new();
+        let mut buf = [0; 8];
+        assert!(ring.push(&packet(1, 9)));
+        assert!(ring.push(&packet(2, 8)));
+        assert_eq!(ring.pop(&mut buf), Some(8));
+        assert_eq!(buf[..], packet(2, 8));
+        assert_eq!(ring.pop(&mut buf), None);
+    }
+
+    #[test]
+    fn drops_everything_on_a_length_beyond_the_written_data() {
+        let ring = Ring::new();
+        assert!(ring.push(&packet(1, 10)));
+        assert!(ring.push(&packet(2, 10)));
+        // The first length claims more than the two packets hold.
+        ring.copy_in(0, &30u16.to_le_bytes());
+        let mut buf = [0; 300];
+        assert_eq!(ring.pop(&mut buf), None);
+        assert_eq!(ring.tail.load(Ordering::Relaxed), ring.head.load(Ordering::Relaxed));
+        assert!(ring.push(&packet(3, 10)));
+        assert_eq!(ring.pop(&mut buf), Some(10));
+        assert_eq!(buf[..10], packet(3, 10));
+    }
+
+    #[test]
+    fn drops_everything_on_corrupt_counters() {
+        let ring = Ring::new();
+        ring.head.store(RING_SIZE as u32 + 1, Ordering::Relaxed);
+        let mut buf = [0; 300];
+        assert_eq!(ring.pop(&mut buf), None);
+        assert_eq!(ring.tail.load(Ordering::Relaxed), RING_SIZE as u32 + 1);
+        ring.head.store(RING_SIZE as u32 + 2, Ordering::Relaxed);
+        assert_eq!(ring.pop(&mut buf), None);
+        assert_eq!(ring.tail.load(Ordering::Relaxed), RING_SIZE as u32 + 2);
+    }
+}
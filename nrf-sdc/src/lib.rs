@@ -1,12 +1,49 @@
-#![no_std]
+#![cfg_attr(not(test), no_std)]
 
 // Must be a top!
 mod fmt;
 
 pub use nrf_sdc_sys as raw;
 
+#[cfg(not(any(
+    feature = "nrf52805",
+    feature = "nrf52810",
+    feature = "nrf52811",
+    feature = "nrf52820",
+    feature = "nrf52832",
+    feature = "nrf52833",
+    feature = "nrf52840",
+    feature = "nrf5340-net",
+    feature = "nrf54l15",
+)))]
+compile_error!("Enable the feature of the chip, such as `nrf52840`.");
+
+mod util;
+
+#[cfg(any(feature = "peripheral", feature = "multirole"))]
+pub mod adv;
+pub mod adv_data;
+#[cfg(any(feature = "central", feature = "multirole"))]
+pub mod central;
+pub mod coex;
+pub mod conn;
+pub mod cte;
+pub mod cx;
+pub mod dtm;
+pub mod hci;
+#[cfg(feature = "nrf5340-net")]
+pub mod ipc;
+pub mod llpm;
 pub mod mpsl;
+#[cfg(feature = "multirole")]
+pub mod periodic;
+pub mod power;
+pub mod qos;
+#[cfg(any(feature = "central", feature = "multirole"))]
+pub mod scan;
 pub mod sdc;
+pub mod security;
+pub mod tx_power;
 
 #[derive(Debug)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
@@ -15,6 +52,12 @@ pub enum Error {
     NotPermitted,
     OpNotSupported,
     Again,
+    NoMem,
+    Timeout,
+    /// The connection was closed, see [`conn::Connection::disconnect_reason`].
+    Disconnected,
+    /// The controller rejected an HCI command with the given status code.
+    Hci(u8),
     Other,
 }
 
@@ -22,9 +65,11 @@ impl From<i32> for Error {
     fn from(val: i32) -> Self {
         match val {
             -1 => Self::NotPermitted,
+            -12 => Self::NoMem,
             -22 => Self::InvalidArg,
             -35 => Self::Again,
             -45 => Self::OpNotSupported,
+            -60 => Self::Timeout,
             _ => Self::Other,
         }
     }
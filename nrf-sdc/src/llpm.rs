@@ -0,0 +1,211 @@
+//! Low Latency Packet Mode (LLPM), allowing connection intervals down to 1 ms
+//! between Nordic devices.
+//!
+//! Intervals are requested in microseconds with
+//! [`Connection::update_interval_us`](crate::conn::Connection::update_interval_us),
+//! which is only available to the central. The event length of a connection
+//! must fit in its interval, and is fixed when the connection is established,
+//! so short intervals need [`set_event_length`] before connecting.
+use super::hci;
+use super::raw;
+use super::Error;
+use core::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+
+static LLPM_ENABLED: AtomicBool = AtomicBool::new(false);
+static EVENT_LENGTH_US: AtomicU32 = AtomicU32::new(raw::SDC_DEFAULT_EVENT_LENGTH_US);
+static MAX_EVENT_LENGTH_US: AtomicU32 = AtomicU32::new(raw::SDC_DEFAULT_EVENT_LENGTH_US);
+
+/// Why a connection interval request was rejected.
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum IntervalError {
+    /// Intervals below 7.5 ms need LLPM, see [`set_llpm_enabled`].
+    LlpmDisabled,
+    /// The interval is neither 1 to 7 ms in steps of 1 ms, nor 7.5 ms to 4 s
+    /// in steps of 1.25 ms.
+    InvalidInterval,
+    /// The event length of the connection, fixed when it was established, is
+    /// longer than the interval.
+    EventLengthTooLong { event_length_us: u32 },
+    /// The supervision timeout is outside 100 ms to 32 s, or not longer than
+    /// `(1 + latency) * interval * 2`.
+    InvalidSupervisionTimeout,
+    /// Only the central can request intervals in microseconds.
+    NotCentral,
+    /// The controller rejected the request or the connection was closed.
+    Controller(Error),
+}
+
+impl From<Error> for IntervalError {
+    fn from(e: Error) -> Self {
+        Self::Controller(e)
+    }
+}
+
+/// Connection parameters with the interval in microseconds.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct IntervalParams {
+    pub interval_us: u32,
+    /// Peripheral latency in number of connection events.
+    pub latency: u16,
+    /// Supervision timeout in units of 10 ms.
+    pub supervision_timeout: u16,
+}
+
+/// Enable or disable LLPM. Disabled after init.
+pub fn set_llpm_enabled(enable: bool) -> Result<(), Error> {
+    let params = raw::sdc_hci_cmd_vs_llpm_mode_set_t { enable: enable as u8 };
+    hci::status(unsafe { raw::sdc_hci_cmd_vs_llpm_mode_set(&params) })?;
+    LLPM_ENABLED.store(enable, Ordering::Relaxed);
+    Ok(())
+}
+
+pub fn is_llpm_enabled() -> bool {
+    LLPM_ENABLED.load(Ordering::Relaxed)
+}
+
+/// Set the event length of connections established from now on, in
+/// microseconds. At most the `event_length_us` of the
+/// [`sdc::Config`](crate::sdc::Config), which is also the initial value,
+/// longer ones are rejected with [`Error::InvalidArg`].
+pub fn set_event_length(event_length_us: u32) -> Result<(), Error> {
+    if event_length_us > MAX_EVENT_LENGTH_US.load(Ordering::Relaxed) {
+        return Err(Error::InvalidArg);
+    }
+    let params = raw::sdc_hci_cmd_vs_event_length_set_t { event_length_us };
+    hci::status(unsafe { raw::sdc_hci_cmd_vs_event_length_set(&params) })?;
+    EVENT_LENGTH_US.store(event_length_us, Ordering::Relaxed);
+    Ok(())
+}
+
+/// The event length of connections established from now on, in microseconds.
+pub fn event_length() -> u32 {
+    EVENT_LENGTH_US.load(Ordering::Relaxed)
+}
+
+pub(crate) fn init(event_length_us: u32) {
+    LLPM_ENABLED.store(false, Ordering::Relaxed);
+    EVENT_LENGTH_US.store(event_length_us, Ordering::Relaxed);
+    MAX_EVENT_LENGTH_US.store(event_length_us, Ordering::Relaxed);
+}
+
+#[cfg(any(feature = "central", feature = "multirole"))]
+fn check(params: &IntervalParams, event_length_us: u32, llpm_enabled: bool) -> Result<(), IntervalError> {
+    // Interval limits in microseconds.
+    const LLPM_INTERVAL_MIN: u32 = 1_000;
+    const LLPM_INTERVAL_MAX: u32 = 7_000;
+    const LLPM_INTERVAL_STEP: u32 = 1_000;
+    const INTERVAL_MIN: u32 = 7_500;
+    const INTERVAL_MAX: u32 = 4_000_000;
+    const INTERVAL_STEP: u32 = 1_250;
+    // Supervision timeout limits in units of 10 ms.
+    const SUPERVISION_TIMEOUT_MIN: u16 = 10;
+    const SUPERVISION_TIMEOUT_MAX: u16 = 3200;
+
+    let interval = params.interval_us;
+    if interval < INTERVAL_MIN {
+        if !(LLPM_INTERVAL_MIN..=LLPM_INTERVAL_MAX).contains(&interval) || interval % LLPM_INTERVAL_STEP != 0 {
+            return Err(IntervalError::InvalidInterval);
+        }
+        if !llpm_enabled {
+            return Err(IntervalError::LlpmDisabled);
+        }
+    } else if interval > INTERVAL_MAX || interval % INTERVAL_STEP != 0 {
+        return Err(IntervalError::InvalidInterval);
+    }
+
+    if event_length_us > interval {
+        return Err(IntervalError::EventLengthTooLong { event_length_us });
+    }
+
+    let timeout = params.supervision_timeout;
+    let min_timeout_us = (1 + params.latency as u64) * interval as u64 * 2;
+    if !(SUPERVISION_TIMEOUT_MIN..=SUPERVISION_TIMEOUT_MAX).contains(&timeout)
+        || timeout as u64 * 10_000 <= min_timeout_us
+    {
+        return Err(IntervalError::InvalidSupervisionTimeout);
+    }
+    Ok(())
+}
+
+/// Check and request `params` on the connection `handle`, with the given event length.
+#[cfg(any(feature = "central", feature = "multirole"))]
+pub(crate) fn conn_update(handle: u16, event_length_us: u32, params: &IntervalParams) -> Result<(), IntervalError> {
+    check(params, event_length_us, is_llpm_enabled())?;
+    let cmd = raw::sdc_hci_cmd_vs_conn_update_t {
+        connection_handle: handle,
+        conn_interval_us: params.interval_us,
+        conn_latency: params.latency,
+        supervision_timeout: params.supervision_timeout,
+    };
+    hci::status(unsafe { raw::sdc_hci_cmd_vs_conn_update(&cmd) })?;
+    Ok(())
+}
+
+#[cfg(all(test, any(feature = "central", feature = "multirole")))]
+mod tests {
+    use super::*;
+
+    fn params(interval_us: u32, latency: u16, supervision_timeout: u16) -> IntervalParams {
+        IntervalParams {
+            interval_us,
+            latency,
+            supervision_timeout,
+        }
+    }
+
+    #[test]
+    fn accepts_llpm_intervals_only_when_enabled() {
+        for interval in [1_000, 2_000, 7_000] {
+            assert!(check(&params(interval, 0, 100), 1_000, true).is_ok());
+            assert!(matches!(
+                check(&params(interval, 0, 100), 1_000, false),
+                Err(IntervalError::LlpmDisabled)
+            ));
+        }
+    }
+
+    #[test]
+    fn rejects_intervals_off_the_steps() {
+        for interval in [0, 500, 1_500, 6_500, 7_250, 8_000, 4_001_250] {
+            assert!(
+                matches!(
+                    check(&params(interval, 0, 3200), 0, true),
+                    Err(IntervalError::InvalidInterval)
+                ),
+                "{interval}"
+            );
+        }
+        for interval in [7_500, 8_750, 4_000_000] {
+            assert!(check(&params(interval, 0, 3200), 0, false).is_ok(), "{interval}");
+        }
+    }
+
+    #[test]
+    fn rejects_event_lengths_longer_than_the_interval() {
+        assert!(check(&params(2_000, 0, 100), 2_000, true).is_ok());
+        assert!(matches!(
+            check(&params(2_000, 0, 100), 2_500, true),
+            Err(IntervalError::EventLengthTooLong { event_length_us: 2_500 })
+        ));
+    }
+
+    #[test]
+    fn checks_the_supervision_timeout() {
+        assert!(matches!(
+            check(&params(7_500, 0, 9), 0, false),
+            Err(IntervalError::InvalidSupervisionTimeout)
+        ));
+        assert!(matches!(
+            check(&params(7_500, 0, 3201), 0, false),
+            Err(IntervalError::InvalidSupervisionTimeout)
+        ));
+        // The timeout must be longer than (1 + 9) * 50 ms * 2 = 1 s.
+        assert!(matches!(
+            check(&params(50_000, 9, 100), 0, false),
+            Err(IntervalError::InvalidSupervisionTimeout)
+        ));
+        assert!(check(&params(50_000, 9, 101), 0, false).is_ok());
+    }
+}
@@ -36,11 +36,7 @@ impl Into<u8> for LfClock {
 pub fn mpsl_init<T, I>(config: Config, _irq: I) -> Result<(), Error>
 where
     T: Interrupt,
-    I: Binding<T, LowPrioInterruptHandler>
-        + Binding<interrupt::typelevel::RADIO, HighPrioInterruptHandler>
-        + Binding<interrupt::typelevel::TIMER0, HighPrioInterruptHandler>
-        + Binding<interrupt::typelevel::RTC0, HighPrioInterruptHandler>
-        + Binding<interrupt::typelevel::POWER_CLOCK, ClockInterruptHandler>,
+    I: Irqs<T>,
 {
     // Default values
     let clock_config = raw::mpsl_clock_lfclk_cfg_t {
@@ -84,32 +80,76 @@ impl<T: Interrupt> Handler<T> for LowPrioInterruptHandler {
 }
 
 pub struct ClockInterruptHandler;
-impl Handler<interrupt::typelevel::POWER_CLOCK> for ClockInterruptHandler {
-    unsafe fn on_interrupt() {
-        raw::MPSL_IRQ_CLOCK_Handler();
-    }
-}
 
 // High priority interrupts
 pub struct HighPrioInterruptHandler;
-impl Handler<interrupt::typelevel::RADIO> for HighPrioInterruptHandler {
-    unsafe fn on_interrupt() {
-        raw::MPSL_IRQ_RADIO_Handler();
-    }
-}
 
-impl Handler<interrupt::typelevel::TIMER0> for HighPrioInterruptHandler {
-    unsafe fn on_interrupt() {
-        raw::MPSL_IRQ_TIMER0_Handler();
-    }
-}
+/// The handlers of the interrupts MPSL uses on a chip family, and [`Irqs`]
+/// requiring all of them to be bound.
+macro_rules! interrupts {
+    (clock: $clock:ident, radio: $radio:ident, timer: $timer:ident, rtc: $rtc:ident) => {
+        /// The interrupts MPSL uses on this chip bound to their handlers, and
+        /// `T` bound to the [`LowPrioInterruptHandler`].
+        pub trait Irqs<T: Interrupt>:
+            Binding<T, LowPrioInterruptHandler>
+            + Binding<interrupt::typelevel::$clock, ClockInterruptHandler>
+            + Binding<interrupt::typelevel::$radio, HighPrioInterruptHandler>
+            + Binding<interrupt::typelevel::$timer, HighPrioInterruptHandler>
+            + Binding<interrupt::typelevel::$rtc, HighPrioInterruptHandler>
+        {
+        }
 
-impl Handler<interrupt::typelevel::RTC0> for HighPrioInterruptHandler {
-    unsafe fn on_interrupt() {
-        raw::MPSL_IRQ_RTC0_Handler();
-    }
+        impl<T: Interrupt, I> Irqs<T> for I where
+            I: Binding<T, LowPrioInterruptHandler>
+                + Binding<interrupt::typelevel::$clock, ClockInterruptHandler>
+                + Binding<interrupt::typelevel::$radio, HighPrioInterruptHandler>
+                + Binding<interrupt::typelevel::$timer, HighPrioInterruptHandler>
+                + Binding<interrupt::typelevel::$rtc, HighPrioInterruptHandler>
+        {
+        }
+
+        impl Handler<interrupt::typelevel::$clock> for ClockInterruptHandler {
+            unsafe fn on_interrupt() {
+                raw::MPSL_IRQ_CLOCK_Handler();
+            }
+        }
+
+        impl Handler<interrupt::typelevel::$radio> for HighPrioInterruptHandler {
+            unsafe fn on_interrupt() {
+                raw::MPSL_IRQ_RADIO_Handler();
+            }
+        }
+
+        impl Handler<interrupt::typelevel::$timer> for HighPrioInterruptHandler {
+            unsafe fn on_interrupt() {
+                raw::MPSL_IRQ_TIMER0_Handler();
+            }
+        }
+
+        impl Handler<interrupt::typelevel::$rtc> for HighPrioInterruptHandler {
+            unsafe fn on_interrupt() {
+                raw::MPSL_IRQ_RTC0_Handler();
+            }
+        }
+    };
 }
 
+#[cfg(any(
+    feature = "nrf52805",
+    feature = "nrf52810",
+    feature = "nrf52811",
+    feature = "nrf52820",
+    feature = "nrf52832",
+    feature = "nrf52833",
+    feature = "nrf52840",
+))]
+interrupts!(clock: POWER_CLOCK, radio: RADIO, timer: TIMER0, rtc: RTC0);
+#[cfg(feature = "nrf5340-net")]
+interrupts!(clock: CLOCK_POWER, radio: RADIO, timer: TIMER0, rtc: RTC0);
+// The GRTC takes the place of RTC0.
+#[cfg(feature = "nrf54l15")]
+interrupts!(clock: CLOCK_POWER, radio: RADIO_0, timer: TIMER10, rtc: GRTC_3);
+
 static MPSL_WAKER: AtomicWaker = AtomicWaker::new();
 pub async fn mpsl_run() {
     poll_fn(|cx| {
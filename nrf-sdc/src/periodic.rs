@@ -0,0 +1,868 @@
+//! Periodic advertising, and synchronization to periodic advertisers.
+//!
+//! Requires [`sdc_run`](crate::sdc::sdc_run) to be running, and
+//! `periodic_adv_count` and `periodic_sync_count` in the
+//! [`sdc::Config`](crate::sdc::Config) to cover the number of trains in use.
+//! Synchronizing also requires a [`Scan`](crate::scan::Scan) to be running
+//! until the sync is established.
+use super::adv::{AdvSet, OP_COMPLETE, OP_FIRST, OP_INTERMEDIATE, OP_LAST};
+use super::adv_data::{self, AdStructures};
+use super::conn;
+use super::cte::{self, CteParams};
+use super::hci::{self, AddrKind, Address, Event, LeEvent, Phy};
+use super::raw;
+use super::util::OnDrop;
+use super::Error;
+use core::cell::{Cell, RefCell};
+use core::future::{pending, poll_fn, Future};
+use core::pin::{pin, Pin};
+use core::task::{Context, Poll};
+use embassy_sync::blocking_mutex::CriticalSectionMutex;
+use embassy_sync::waitqueue::AtomicWaker;
+use futures_core::Stream;
+
+/// Maximum number of periodic advertising trains synchronized to at once.
+pub const MAX_SYNCS: usize = 4;
+
+/// Maximum length of a reassembled periodic advertising payload.
+pub const MAX_REPORT_LEN: usize = adv_data::EXTENDED_MAX_LEN;
+
+/// Maximum data carried by a single LE Set Periodic Advertising Data command.
+const MAX_FRAGMENT_LEN: usize = 252;
+
+const DATA_STATUS_COMPLETE: u8 = 0;
+const DATA_STATUS_INCOMPLETE: u8 = 1;
+
+pub(crate) const STATUS_MEMORY_CAPACITY_EXCEEDED: u8 = 0x07;
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct PeriodicAdvParams {
+    /// Minimum periodic advertising interval in units of 1.25 ms.
+    pub interval_min: u16,
+    /// Maximum periodic advertising interval in units of 1.25 ms.
+    pub interval_max: u16,
+    /// Include the TX power in the periodic advertising header.
+    pub include_tx_power: bool,
+}
+
+impl Default for PeriodicAdvParams {
+    fn default() -> Self {
+        Self {
+            interval_min: 80,
+            interval_max: 80,
+            include_tx_power: false,
+        }
+    }
+}
+
+/// Periodic advertising on an extended, non-connectable and non-scannable
+/// advertising set.
+///
+/// The periodic advertising train only starts once the set itself is
+/// advertising, see [`adv_set`](Self::adv_set). It is stopped when dropped.
+pub struct PeriodicAdvertiser<'s, 'a> {
+    set: &'s mut AdvSet<'a>,
+}
+
+impl<'s, 'a> PeriodicAdvertiser<'s, 'a> {
+    pub fn new(set: &'s mut AdvSet<'a>, params: &PeriodicAdvParams) -> Result<Self, Error> {
+        let mut this = Self { set };
+        this.set_params(params)?;
+        Ok(this)
+    }
+
+    /// Handle of the underlying advertising set.
+    pub fn adv_handle(&self) -> u8 {
+        self.set.handle()
+    }
+
+    /// The underlying advertising set.
+    pub fn adv_set(&mut self) -> &mut AdvSet<'a> {
+        self.set
+    }
+
+    /// Update the parameters. Periodic advertising must be stopped.
+    pub fn set_params(&mut self, params: &PeriodicAdvParams) -> Result<(), Error> {
+        let cmd = raw::sdc_hci_cmd_le_set_periodic_adv_params_t {
+            adv_handle: self.set.handle(),
+            periodic_adv_interval_min: params.interval_min,
+            periodic_adv_interval_max: params.interval_max,
+            periodic_adv_properties: if params.include_tx_power { 0x40 } else { 0 },
+        };
+        hci::status(unsafe { raw::sdc_hci_cmd_le_set_periodic_adv_params(&cmd) })
+    }
+
+    /// Set the periodic advertising data, split into as many commands as needed.
+    ///
+    /// While advertising, the data must fit in a single command.
+    pub fn set_data(&mut self, data: &[u8]) -> Result<(), Error> {
+        let mut buf = [0; 3 + MAX_FRAGMENT_LEN];
+        let count = core::cmp::max(1, data.len().div_ceil(MAX_FRAGMENT_LEN));
+        for i in 0..count {
+            let fragment = &data[i * MAX_FRAGMENT_LEN..core::cmp::min(data.len(), (i + 1) * MAX_FRAGMENT_LEN)];
+            buf[0] = self.set.handle();
+            buf[1] = match i {
+                _ if count == 1 => OP_COMPLETE,
+                0 => OP_FIRST,
+                _ if i == count - 1 => OP_LAST,
+                _ => OP_INTERMEDIATE,
+            };
+            buf[2] = fragment.len() as u8;
+            buf[3..3 + fragment.len()].copy_from_slice(fragment);
+            hci::status(unsafe { raw::sdc_hci_cmd_le_set_periodic_adv_data(buf.as_ptr() as *const _) })?;
+        }
+        Ok(())
+    }
+
+    /// Start periodic advertising, including the ADI field in the periodic
+    /// advertising header if `include_adi` is set.
+    pub fn start(&mut self, include_adi: bool) -> Result<(), Error> {
+        set_enable(self.set.handle(), if include_adi { 0x03 } else { 0x01 })
+    }
+
+    pub fn stop(&mut self) -> Result<(), Error> {
+        set_enable(self.set.handle(), 0)
+    }
+
+    /// Configure the CTEs sent with the periodic advertising. Fails with
+    /// [`Error::InvalidArg`] if the controller's antenna array cannot send them.
+    pub fn set_cte(&mut self, params: &CteParams) -> Result<(), Error> {
+        cte::set_connless_params(self.set.handle(), params)
+    }
+
+    /// Start or stop sending CTEs, configured with [`set_cte`](Self::set_cte).
+    pub fn set_cte_enabled(&mut self, enable: bool) -> Result<(), Error> {
+        cte::set_connless_enable(self.set.handle(), enable)
+    }
+}
+
+impl<'s, 'a> Drop for PeriodicAdvertiser<'s, 'a> {
+    fn drop(&mut self) {
+        if let Err(e) = self.stop() {
+            warn!("[periodic] failed to stop set {}: {:?}", self.set.handle(), e);
+        }
+    }
+}
+
+fn set_enable(handle: u8, enable: u8) -> Result<(), Error> {
+    let cmd = raw::sdc_hci_cmd_le_set_periodic_adv_enable_t {
+        enable,
+        adv_handle: handle,
+    };
+    hci::status(unsafe { raw::sdc_hci_cmd_le_set_periodic_adv_enable(&cmd) })
+}
+
+/// Which periodic advertiser to synchronize to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum SyncTarget {
+    Advertiser {
+        addr: Address,
+        sid: u8,
+    },
+    /// Synchronize to the first advertiser found in the periodic advertiser list.
+    PeriodicAdvList,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SyncParams {
+    /// Number of periodic advertising events that may be skipped.
+    pub skip: u16,
+    /// Synchronization timeout in units of 10 ms.
+    pub timeout: u16,
+    /// Report the received periodic advertising once synchronized.
+    pub reports_enabled: bool,
+    /// Let the controller drop duplicate reports.
+    pub filter_duplicates: bool,
+}
+
+impl Default for SyncParams {
+    fn default() -> Self {
+        Self {
+            skip: 0,
+            timeout: 1000,
+            reports_enabled: true,
+            filter_duplicates: false,
+        }
+    }
+}
+
+/// What the controller does with periodic sync information received from a peer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum SyncTransferMode {
+    /// Ignore the transfers.
+    Ignore = 0,
+    /// Synchronize with reports initially disabled.
+    NoReports = 1,
+    Reports = 2,
+    /// Synchronize with reports and duplicate filtering initially enabled.
+    ReportsFilterDuplicates = 3,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SyncTransferParams {
+    pub mode: SyncTransferMode,
+    /// Number of periodic advertising events that may be skipped.
+    pub skip: u16,
+    /// Synchronization timeout in units of 10 ms.
+    pub timeout: u16,
+}
+
+impl Default for SyncTransferParams {
+    fn default() -> Self {
+        Self {
+            mode: SyncTransferMode::Reports,
+            skip: 0,
+            timeout: 1000,
+        }
+    }
+}
+
+/// Set how transfers are handled on connections established from now on.
+/// Use [`Connection::set_sync_transfer_params`](conn::Connection::set_sync_transfer_params)
+/// for existing connections.
+pub fn set_default_sync_transfer_params(params: &SyncTransferParams) -> Result<(), Error> {
+    enable_transfer_events()?;
+    let cmd = raw::sdc_hci_cmd_le_set_default_periodic_adv_sync_transfer_params_t {
+        mode: params.mode as u8,
+        skip: params.skip,
+        sync_timeout: params.timeout,
+        cte_type: 0,
+    };
+    hci::status(unsafe { raw::sdc_hci_cmd_le_set_default_periodic_adv_sync_transfer_params(&cmd) })
+}
+
+pub(crate) fn enable_transfer_events() -> Result<(), Error> {
+    enable_events()?;
+    hci::enable_le_events(&[hci::SUBEVT_PERIODIC_SYNC_TRANSFER_RECEIVED])
+}
+
+pub(crate) fn set_sync_transfer_params(conn_handle: u16, params: &SyncTransferParams) -> Result<(), Error> {
+    enable_transfer_events()?;
+    let cmd = raw::sdc_hci_cmd_le_set_periodic_adv_sync_transfer_params_t {
+        conn_handle,
+        mode: params.mode as u8,
+        skip: params.skip,
+        sync_timeout: params.timeout,
+        cte_type: 0,
+    };
+    let mut ret = unsafe { core::mem::zeroed::<raw::sdc_hci_cmd_le_set_periodic_adv_sync_transfer_params_return_t>() };
+    hci::status(unsafe { raw::sdc_hci_cmd_le_set_periodic_adv_sync_transfer_params(&cmd, &mut ret) })
+}
+
+pub(crate) fn sync_transfer(conn_handle: u16, sync: &PeriodicSync, service_data: u16) -> Result<(), Error> {
+    let cmd = raw::sdc_hci_cmd_le_periodic_adv_sync_transfer_t {
+        conn_handle,
+        service_data,
+        sync_handle: sync.sync_handle(),
+    };
+    let mut ret = unsafe { core::mem::zeroed::<raw::sdc_hci_cmd_le_periodic_adv_sync_transfer_return_t>() };
+    hci::status(unsafe { raw::sdc_hci_cmd_le_periodic_adv_sync_transfer(&cmd, &mut ret) })
+}
+
+pub(crate) fn set_info_transfer(conn_handle: u16, adv_handle: u8, service_data: u16) -> Result<(), Error> {
+    let cmd = raw::sdc_hci_cmd_le_periodic_adv_set_info_transfer_t {
+        conn_handle,
+        service_data,
+        advertising_handle: adv_handle,
+    };
+    let mut ret = unsafe { core::mem::zeroed::<raw::sdc_hci_cmd_le_periodic_adv_set_info_transfer_return_t>() };
+    hci::status(unsafe { raw::sdc_hci_cmd_le_periodic_adv_set_info_transfer(&cmd, &mut ret) })
+}
+
+/// Periodic advertising data received on a synchronized train, with the data
+/// of all fragments reassembled.
+#[derive(Clone)]
+pub struct PeriodicReport {
+    /// TX power in dBm, `None` if not available.
+    pub tx_power: Option<i8>,
+    /// RSSI in dBm, `None` if not available.
+    pub rssi: Option<i8>,
+    /// Type of the constant tone extension, `None` if not present.
+    pub cte_type: Option<u8>,
+    /// The controller stopped receiving fragments before the data was complete.
+    pub truncated: bool,
+    len: usize,
+    data: [u8; MAX_REPORT_LEN],
+}
+
+impl PeriodicReport {
+    const fn new() -> Self {
+        Self {
+            tx_power: None,
+            rssi: None,
+            cte_type: None,
+            truncated: false,
+            len: 0,
+            data: [0; MAX_REPORT_LEN],
+        }
+    }
+
+    fn start(&mut self, r: &hci::PeriodicAdvReport) {
+        self.tx_power = (r.tx_power != 0x7F).then_some(r.tx_power);
+        self.rssi = (r.rssi != 0x7F).then_some(r.rssi);
+        self.cte_type = (r.cte_type != 0xFF).then_some(r.cte_type);
+        self.truncated = false;
+        self.len = 0;
+    }
+
+    fn append(&mut self, data: &[u8]) {
+        let len = core::cmp::min(data.len(), MAX_REPORT_LEN - self.len);
+        self.data[self.len..self.len + len].copy_from_slice(&data[..len]);
+        self.len += len;
+        self.truncated |= len < data.len();
+    }
+
+    /// The periodic advertising data.
+    pub fn data(&self) -> &[u8] {
+        &self.data[..self.len]
+    }
+
+    /// The AD structures in the data.
+    pub fn ad_structures(&self) -> AdStructures<'_> {
+        adv_data::parse(self.data())
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct SyncInfo {
+    pub(crate) sync_handle: u16,
+    pub(crate) sid: u8,
+    pub(crate) addr: Option<Address>,
+    pub(crate) phy: Phy,
+    pub(crate) interval: u16,
+}
+
+struct SyncSlot {
+    info: Option<SyncInfo>,
+    lost: bool,
+    reassembling: bool,
+    /// The rest of a report that could not be delivered is being skipped.
+    discarding: bool,
+    /// `report` holds a complete report not yet taken by the owner.
+    ready: bool,
+    report: PeriodicReport,
+}
+
+impl SyncSlot {
+    const fn new() -> Self {
+        Self {
+            info: None,
+            lost: false,
+            reassembling: false,
+            discarding: false,
+            ready: false,
+            report: PeriodicReport::new(),
+        }
+    }
+
+    fn is_live(&self, sync_handle: u16) -> bool {
+        matches!(self.info, Some(info) if info.sync_handle == sync_handle) && !self.lost
+    }
+
+    /// Adds a fragment to the report being reassembled, returns whether it
+    /// completed the report.
+    fn on_fragment(&mut self, r: &hci::PeriodicAdvReport) -> bool {
+        if self.discarding {
+            self.discarding = r.data_status == DATA_STATUS_INCOMPLETE;
+            return false;
+        }
+        if self.ready {
+            // The owner has not taken the previous report yet, drop this one
+            // along with its remaining fragments.
+            self.discarding = r.data_status == DATA_STATUS_INCOMPLETE;
+            return false;
+        }
+        if !self.reassembling {
+            self.report.start(r);
+        }
+        self.report.append(r.data);
+        match r.data_status {
+            DATA_STATUS_INCOMPLETE => {
+                self.reassembling = true;
+                false
+            }
+            status => {
+                self.report.truncated |= status != DATA_STATUS_COMPLETE;
+                self.reassembling = false;
+                self.ready = true;
+                true
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum CreateState {
+    Idle,
+    Pending,
+    /// The creating future was dropped, nobody waits for the outcome.
+    Abandoned,
+    /// The outcome of the attempt: the slot index or an HCI status.
+    Done(Result<usize, u8>),
+}
+
+const NEW_SLOT: SyncSlot = SyncSlot::new();
+static SYNCS: CriticalSectionMutex<RefCell<[SyncSlot; MAX_SYNCS]>> =
+    CriticalSectionMutex::new(RefCell::new([NEW_SLOT; MAX_SYNCS]));
+static CREATE: CriticalSectionMutex<Cell<CreateState>> = CriticalSectionMutex::new(Cell::new(CreateState::Idle));
+static CREATE_WAKER: AtomicWaker = AtomicWaker::new();
+
+#[allow(clippy::declare_interior_mutable_const)]
+const NEW_WAKER: AtomicWaker = AtomicWaker::new();
+static WAKERS: [AtomicWaker; MAX_SYNCS] = [NEW_WAKER; MAX_SYNCS];
+
+pub(crate) fn on_event(event: &Event) {
+    match event {
+        Event::Le(LeEvent::PeriodicSyncEstablished(e)) => {
+            let result = match e.status {
+                hci::STATUS_SUCCESS => {
+                    let info = SyncInfo {
+                        sync_handle: e.sync_handle,
+                        sid: e.sid,
+                        addr: AddrKind::from_u8(e.addr_kind).map(|kind| Address { kind, addr: e.addr }),
+                        phy: Phy::from_u8(e.phy).unwrap_or(Phy::Le1M),
+                        interval: e.interval,
+                    };
+                    allocate(info).ok_or(STATUS_MEMORY_CAPACITY_EXCEEDED)
+                }
+                status => Err(status),
+            };
+            let orphan = CREATE.lock(|state| match state.get() {
+                CreateState::Pending => {
+                    state.set(CreateState::Done(result));
+                    None
+                }
+                CreateState::Abandoned => {
+                    state.set(CreateState::Idle);
+                    result.ok()
+                }
+                _ => result.ok(),
+            });
+            if let Some(index) = orphan {
+                // The sync was established while the attempt was being cancelled.
+                debug!("[periodic] terminating abandoned sync {}", e.sync_handle);
+                release(index);
+            } else if result == Err(STATUS_MEMORY_CAPACITY_EXCEEDED) {
+                let _ = terminate(e.sync_handle);
+            }
+            CREATE_WAKER.wake();
+        }
+        Event::Le(LeEvent::PeriodicSyncTransferReceived(t)) => {
+            let result = match t.status {
+                hci::STATUS_SUCCESS => {
+                    let info = SyncInfo {
+                        sync_handle: t.sync_handle,
+                        sid: t.sid,
+                        addr: AddrKind::from_u8(t.addr_kind).map(|kind| Address { kind, addr: t.addr }),
+                        phy: Phy::from_u8(t.phy).unwrap_or(Phy::Le1M),
+                        interval: t.interval,
+                    };
+                    match allocate(info) {
+                        Some(index) => Ok(index),
+                        None => {
+                            let _ = terminate(t.sync_handle);
+                            Err(STATUS_MEMORY_CAPACITY_EXCEEDED)
+                        }
+                    }
+                }
+                status => Err(status),
+            };
+            if let Some(index) = conn::deliver_sync_transfer(t.conn_handle, result.map(|i| (i, t.service_data))) {
+                debug!("[periodic] terminating unclaimed transferred sync {}", t.sync_handle);
+                release(index);
+            }
+        }
+        Event::Le(LeEvent::PeriodicAdvReport(r)) => {
+            let index = SYNCS.lock(|syncs| {
+                let mut syncs = syncs.borrow_mut();
+                let index = syncs.iter().position(|s| s.is_live(r.sync_handle))?;
+                syncs[index].on_fragment(r).then_some(index)
+            });
+            if let Some(index) = index {
+                WAKERS[index].wake();
+            }
+        }
+        Event::Le(LeEvent::PeriodicSyncLost { sync_handle }) => {
+            let index = SYNCS.lock(|syncs| {
+                let mut syncs = syncs.borrow_mut();
+                let index = syncs.iter().position(|s| s.is_live(*sync_handle))?;
+                syncs[index].lost = true;
+                Some(index)
+            });
+            if let Some(index) = index {
+                WAKERS[index].wake();
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Track a newly established sync.
+pub(crate) fn allocate(info: SyncInfo) -> Option<usize> {
+    SYNCS.lock(|syncs| {
+        let mut syncs = syncs.borrow_mut();
+        let index = syncs.iter().position(|s| s.info.is_none())?;
+        syncs[index] = SyncSlot {
+            info: Some(info),
+            ..SyncSlot::new()
+        };
+        Some(index)
+    })
+}
+
+/// Terminate the sync tracked at `index` unless lost, and free the slot.
+pub(crate) fn release(index: usize) {
+    let live = SYNCS.lock(|syncs| {
+        let mut syncs = syncs.borrow_mut();
+        let slot = core::mem::replace(&mut syncs[index], SyncSlot::new());
+        slot.info.filter(|_| !slot.lost)
+    });
+    if let Some(info) = live {
+        if let Err(e) = terminate(info.sync_handle) {
+            warn!("[periodic] failed to terminate sync {}: {:?}", info.sync_handle, e);
+        }
+    }
+}
+
+fn terminate(sync_handle: u16) -> Result<(), Error> {
+    let cmd = raw::sdc_hci_cmd_le_periodic_adv_terminate_sync_t { sync_handle };
+    hci::status(unsafe { raw::sdc_hci_cmd_le_periodic_adv_terminate_sync(&cmd) })
+}
+
+fn take_done() -> Option<Result<usize, u8>> {
+    CREATE.lock(|state| match state.get() {
+        CreateState::Done(result) => {
+            state.set(CreateState::Idle);
+            Some(result)
+        }
+        _ => None,
+    })
+}
+
+fn abandon() {
+    let done = CREATE.lock(|state| match state.get() {
+        CreateState::Pending => {
+            state.set(CreateState::Abandoned);
+            None
+        }
+        CreateState::Done(result) => {
+            state.set(CreateState::Idle);
+            Some(result)
+        }
+        _ => None,
+    });
+    match done {
+        None => {
+            let _ = unsafe { raw::sdc_hci_cmd_le_periodic_adv_create_sync_cancel() };
+        }
+        Some(Ok(index)) => release(index),
+        Some(Err(_)) => {}
+    }
+}
+
+pub(crate) fn enable_events() -> Result<(), Error> {
+    hci::enable_le_events(&[
+        hci::SUBEVT_PERIODIC_SYNC_ESTABLISHED,
+        hci::SUBEVT_PERIODIC_ADV_REPORT,
+        hci::SUBEVT_PERIODIC_SYNC_LOST,
+    ])
+}
+
+/// A synchronized periodic advertising train, yielding the received reports.
+///
+/// The sync is terminated when dropped.
+pub struct PeriodicSync {
+    index: usize,
+    info: SyncInfo,
+}
+
+impl PeriodicSync {
+    /// Synchronize to a periodic advertiser, waiting as long as it takes.
+    ///
+    /// Only one synchronization can be pending at a time, otherwise this fails
+    /// with [`Error::Again`]. Dropping the returned future cancels the attempt.
+    pub async fn create(target: SyncTarget, params: &SyncParams) -> Result<Self, Error> {
+        Self::create_with_timeout(target, params, pending::<()>()).await
+    }
+
+    /// Synchronize to a periodic advertiser, cancelling the attempt once
+    /// `timeout` completes, for instance an `embassy_time::Timer`.
+    pub async fn create_with_timeout(
+        target: SyncTarget,
+        params: &SyncParams,
+        timeout: impl Future,
+    ) -> Result<Self, Error> {
+        enable_events()?;
+        let idle = CREATE.lock(|state| match state.get() {
+            CreateState::Idle => {
+                state.set(CreateState::Pending);
+                true
+            }
+            _ => false,
+        });
+        if !idle {
+            return Err(Error::Again);
+        }
+        if let Err(e) = create_sync(target, params) {
+            CREATE.lock(|state| state.set(CreateState::Idle));
+            return Err(e);
+        }
+        let on_drop = OnDrop::new(abandon);
+
+        let mut timeout = pin!(timeout);
+        let done = poll_fn(|cx| {
+            CREATE_WAKER.register(cx.waker());
+            if let Some(result) = take_done() {
+                return Poll::Ready(Some(result));
+            }
+            match timeout.as_mut().poll(cx) {
+                Poll::Ready(_) => Poll::Ready(None),
+                Poll::Pending => Poll::Pending,
+            }
+        })
+        .await;
+
+        let result = match done {
+            Some(result) => result,
+            None => {
+                // The controller reports the outcome in an event either way.
+                let ret = unsafe { raw::sdc_hci_cmd_le_periodic_adv_create_sync_cancel() };
+                if ret != hci::STATUS_SUCCESS && ret != hci::STATUS_COMMAND_DISALLOWED {
+                    warn!("[periodic] cancel failed: {}", ret);
+                }
+                poll_fn(|cx| {
+                    CREATE_WAKER.register(cx.waker());
+                    match take_done() {
+                        Some(result) => Poll::Ready(result),
+                        None => Poll::Pending,
+                    }
+                })
+                .await
+            }
+        };
+        on_drop.defuse();
+
+        match result {
+            Ok(index) => Ok(Self::from_index(index)),
+            Err(hci::STATUS_OPERATION_CANCELLED) => Err(Error::Timeout),
+            Err(STATUS_MEMORY_CAPACITY_EXCEEDED) => Err(Error::NoMem),
+            Err(status) => Err(Error::Hci(status)),
+        }
+    }
+
+    pub(crate) fn from_index(index: usize) -> Self {
+        let info = SYNCS.lock(|syncs| syncs.borrow()[index].info.unwrap());
+        Self { index, info }
+    }
+
+    pub fn sync_handle(&self) -> u16 {
+        self.info.sync_handle
+    }
+
+    /// Advertising SID of the train.
+    pub fn sid(&self) -> u8 {
+        self.info.sid
+    }
+
+    /// Advertiser address, `None` if not resolved to a known kind.
+    pub fn addr(&self) -> Option<Address> {
+        self.info.addr
+    }
+
+    pub fn phy(&self) -> Phy {
+        self.info.phy
+    }
+
+    /// Periodic advertising interval in units of 1.25 ms.
+    pub fn interval(&self) -> u16 {
+        self.info.interval
+    }
+
+    /// Whether the controller lost the synchronization.
+    pub fn is_lost(&self) -> bool {
+        SYNCS.lock(|syncs| syncs.borrow()[self.index].lost)
+    }
+
+    /// Enable or disable reporting of the received periodic advertising.
+    pub fn set_reports_enabled(&mut self, enable: bool, filter_duplicates: bool) -> Result<(), Error> {
+        let cmd = raw::sdc_hci_cmd_le_set_periodic_adv_receive_enable_t {
+            sync_handle: self.info.sync_handle,
+            enable: enable as u8 | (filter_duplicates as u8) << 1,
+        };
+        hci::status(unsafe { raw::sdc_hci_cmd_le_set_periodic_adv_receive_enable(&cmd) })
+    }
+
+    /// Wait for the next report, or `None` once the sync is lost.
+    pub async fn next(&mut self) -> Option<PeriodicReport> {
+        poll_fn(|cx| self.poll_report(cx)).await
+    }
+
+    /// Wait until the sync is lost.
+    pub async fn lost(&self) {
+        poll_fn(|cx| {
+            WAKERS[self.index].register(cx.waker());
+            match self.is_lost() {
+                true => Poll::Ready(()),
+                false => Poll::Pending,
+            }
+        })
+        .await
+    }
+
+    fn poll_report(&self, cx: &mut Context<'_>) -> Poll<Option<PeriodicReport>> {
+        WAKERS[self.index].register(cx.waker());
+        SYNCS.lock(|syncs| {
+            let mut syncs = syncs.borrow_mut();
+            let slot = &mut syncs[self.index];
+            if slot.ready {
+                slot.ready = false;
+                Poll::Ready(Some(slot.report.clone()))
+            } else if slot.lost {
+                Poll::Ready(None)
+            } else {
+                Poll::Pending
+            }
+        })
+    }
+}
+
+impl Stream for PeriodicSync {
+    type Item = PeriodicReport;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<PeriodicReport>> {
+        self.poll_report(cx)
+    }
+}
+
+impl Drop for PeriodicSync {
+    fn drop(&mut self) {
+        release(self.index);
+    }
+}
+
+fn create_sync(target: SyncTarget, params: &SyncParams) -> Result<(), Error> {
+    let (list, addr, sid) = match target {
+        SyncTarget::Advertiser { addr, sid } => (false, addr, sid),
+        SyncTarget::PeriodicAdvList => (
+            true,
+            Address {
+                kind: AddrKind::Public,
+                addr: [0; 6],
+            },
+            0,
+        ),
+    };
+    let cmd = raw::sdc_hci_cmd_le_periodic_adv_create_sync_t {
+        options: list as u8 | (!params.reports_enabled as u8) << 1 | (params.filter_duplicates as u8) << 2,
+        adv_sid: sid,
+        adv_address_type: list_addr_kind(&addr),
+        adv_address: addr.addr,
+        skip: params.skip,
+        sync_timeout: params.timeout,
+        sync_cte_type: 0,
+    };
+    hci::status(unsafe { raw::sdc_hci_cmd_le_periodic_adv_create_sync(&cmd) })
+}
+
+// The periodic advertising commands only distinguish public and random
+// (identity) addresses.
+fn list_addr_kind(addr: &Address) -> u8 {
+    match addr.kind {
+        AddrKind::Public | AddrKind::ResolvablePublic => 0,
+        AddrKind::Random | AddrKind::ResolvableRandom => 1,
+    }
+}
+
+/// Add an advertiser to the periodic advertiser list.
+pub fn periodic_adv_list_add(addr: &Address, sid: u8) -> Result<(), Error> {
+    let params = raw::sdc_hci_cmd_le_add_device_to_periodic_adv_list_t {
+        adv_address_type: list_addr_kind(addr),
+        adv_address: addr.addr,
+        adv_sid: sid,
+    };
+    hci::status(unsafe { raw::sdc_hci_cmd_le_add_device_to_periodic_adv_list(&params) })
+}
+
+pub fn periodic_adv_list_remove(addr: &Address, sid: u8) -> Result<(), Error> {
+    let params = raw::sdc_hci_cmd_le_remove_device_from_periodic_adv_list_t {
+        adv_address_type: list_addr_kind(addr),
+        adv_address: addr.addr,
+        adv_sid: sid,
+    };
+    hci::status(unsafe { raw::sdc_hci_cmd_le_remove_device_from_periodic_adv_list(&params) })
+}
+
+pub fn periodic_adv_list_clear() -> Result<(), Error> {
+    hci::status(unsafe { raw::sdc_hci_cmd_le_clear_periodic_adv_list() })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TRUNCATED: u8 = 2;
+
+    fn fragment(data_status: u8, data: &[u8]) -> hci::PeriodicAdvReport<'_> {
+        hci::PeriodicAdvReport {
+            sync_handle: 0,
+            tx_power: 0x7F,
+            rssi: -50,
+            cte_type: 0xFF,
+            data_status,
+            data,
+        }
+    }
+
+    /// Takes the ready report, like the owner of the sync does.
+    fn take(slot: &mut SyncSlot) -> (Vec<u8>, bool) {
+        assert!(slot.ready);
+        slot.ready = false;
+        (slot.report.data().to_vec(), slot.report.truncated)
+    }
+
+    #[test]
+    fn reassembles_a_report() {
+        let mut slot = SyncSlot::new();
+        assert!(!slot.on_fragment(&fragment(DATA_STATUS_INCOMPLETE, &[1, 2])));
+        assert!(slot.on_fragment(&fragment(DATA_STATUS_COMPLETE, &[3])));
+        assert_eq!(take(&mut slot), (vec![1, 2, 3], false));
+    }
+
+    #[test]
+    fn marks_a_report_cut_short_by_the_controller() {
+        let mut slot = SyncSlot::new();
+        assert!(!slot.on_fragment(&fragment(DATA_STATUS_INCOMPLETE, &[1])));
+        assert!(slot.on_fragment(&fragment(TRUNCATED, &[])));
+        assert_eq!(take(&mut slot), (vec![1], true));
+    }
+
+    #[test]
+    fn drops_a_report_while_one_is_ready_and_resumes_after() {
+        let mut slot = SyncSlot::new();
+        assert!(slot.on_fragment(&fragment(DATA_STATUS_COMPLETE, &[1])));
+        // Not taken yet: the next report is dropped up to its last fragment.
+        assert!(!slot.on_fragment(&fragment(DATA_STATUS_INCOMPLETE, &[2])));
+        assert_eq!(take(&mut slot), (vec![1], false));
+        assert!(!slot.on_fragment(&fragment(DATA_STATUS_INCOMPLETE, &[3])));
+        assert!(!slot.on_fragment(&fragment(DATA_STATUS_COMPLETE, &[4])));
+        // The following report is reassembled again.
+        assert!(!slot.on_fragment(&fragment(DATA_STATUS_INCOMPLETE, &[5])));
+        assert!(slot.on_fragment(&fragment(DATA_STATUS_COMPLETE, &[6])));
+        assert_eq!(take(&mut slot), (vec![5, 6], false));
+    }
+
+    #[test]
+    fn drops_a_single_fragment_report_while_one_is_ready() {
+        let mut slot = SyncSlot::new();
+        assert!(slot.on_fragment(&fragment(DATA_STATUS_COMPLETE, &[1])));
+        assert!(!slot.on_fragment(&fragment(DATA_STATUS_COMPLETE, &[2])));
+        assert_eq!(take(&mut slot), (vec![1], false));
+        assert!(slot.on_fragment(&fragment(DATA_STATUS_COMPLETE, &[3])));
+        assert_eq!(take(&mut slot), (vec![3], false));
+    }
+}
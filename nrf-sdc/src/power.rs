@@ -0,0 +1,302 @@
+//! LE Power Control and path loss monitoring on a connection.
+//!
+//! Requires [`sdc_run`](crate::sdc::sdc_run) to be running.
+use super::conn::{self, Connection, MAX_CONNECTIONS};
+use super::hci::{self, Event, LeEvent};
+use super::raw;
+use super::Error;
+use core::cell::RefCell;
+use core::future::poll_fn;
+use core::task::Poll;
+use embassy_sync::blocking_mutex::CriticalSectionMutex;
+
+const REASON_LOCAL_CHANGE: u8 = 0;
+const REASON_REMOTE_CHANGE: u8 = 1;
+const REASON_READ_REMOTE: u8 = 2;
+
+/// The PHYs power levels are managed for, Coded split by coding scheme.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum PowerPhy {
+    Le1M = 1,
+    Le2M = 2,
+    LeCodedS8 = 3,
+    LeCodedS2 = 4,
+}
+
+impl PowerPhy {
+    fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            1 => Some(Self::Le1M),
+            2 => Some(Self::Le2M),
+            3 => Some(Self::LeCodedS8),
+            4 => Some(Self::LeCodedS2),
+            _ => None,
+        }
+    }
+}
+
+/// Local transmit power levels in dBm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct TxPowerLevel {
+    pub current: i8,
+    pub max: i8,
+}
+
+/// A transmit power level reported by the controller.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct TxPowerReport {
+    pub phy: PowerPhy,
+    /// Power level in dBm, `None` if not available or not managed by the peer.
+    pub level: Option<i8>,
+    pub at_min: bool,
+    pub at_max: bool,
+    /// Change of the power level in dB, `None` if not available.
+    pub delta: Option<i8>,
+}
+
+impl TxPowerReport {
+    fn decode(r: &hci::TransmitPowerReporting) -> Self {
+        Self {
+            phy: PowerPhy::from_u8(r.phy).unwrap_or(PowerPhy::Le1M),
+            level: (r.tx_power != 0x7E && r.tx_power != 0x7F).then_some(r.tx_power),
+            at_min: r.flags & 0x01 != 0,
+            at_max: r.flags & 0x02 != 0,
+            delta: (r.delta != 0x7F).then_some(r.delta),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum PathLossZone {
+    Low = 0,
+    Middle = 1,
+    High = 2,
+}
+
+/// Path loss zone thresholds. Set a threshold to 0xFF to leave it unused.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct PathLossParams {
+    /// High threshold in dB.
+    pub high_threshold: u8,
+    /// High hysteresis in dB.
+    pub high_hysteresis: u8,
+    /// Low threshold in dB.
+    pub low_threshold: u8,
+    /// Low hysteresis in dB.
+    pub low_hysteresis: u8,
+    /// Number of connection events spent in a zone before it is reported.
+    pub min_time_spent: u16,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum PowerEvent {
+    /// The local transmit power changed.
+    Local(TxPowerReport),
+    /// The transmit power of the peer changed.
+    Remote(TxPowerReport),
+    /// The path loss crossed into another zone.
+    PathLoss { path_loss: Option<u8>, zone: PathLossZone },
+}
+
+#[derive(Clone, Copy)]
+struct PowerState {
+    active: bool,
+    local: Option<TxPowerReport>,
+    remote: Option<TxPowerReport>,
+    path_loss: Option<(Option<u8>, PathLossZone)>,
+    read_remote: Option<Result<TxPowerReport, u8>>,
+}
+
+impl PowerState {
+    const fn new() -> Self {
+        Self {
+            active: false,
+            local: None,
+            remote: None,
+            path_loss: None,
+            read_remote: None,
+        }
+    }
+}
+
+// Indexed like the connection table.
+static STATES: CriticalSectionMutex<RefCell<[PowerState; MAX_CONNECTIONS]>> =
+    CriticalSectionMutex::new(RefCell::new([PowerState::new(); MAX_CONNECTIONS]));
+
+pub(crate) fn on_event(event: &Event) {
+    let handle = match event {
+        Event::Le(LeEvent::TransmitPowerReporting(r)) => r.conn_handle,
+        Event::Le(LeEvent::PathLossThreshold { conn_handle, .. }) => *conn_handle,
+        _ => return,
+    };
+    let Some(index) = conn::index_of(handle) else {
+        return;
+    };
+    let active = STATES.lock(|states| {
+        let mut states = states.borrow_mut();
+        let state = &mut states[index];
+        if !state.active {
+            return false;
+        }
+        match event {
+            Event::Le(LeEvent::TransmitPowerReporting(r)) => match (r.reason, r.status) {
+                (REASON_READ_REMOTE, hci::STATUS_SUCCESS) => state.read_remote = Some(Ok(TxPowerReport::decode(r))),
+                (REASON_READ_REMOTE, status) => state.read_remote = Some(Err(status)),
+                (_, status) if status != hci::STATUS_SUCCESS => {}
+                (REASON_LOCAL_CHANGE, _) => state.local = Some(TxPowerReport::decode(r)),
+                (REASON_REMOTE_CHANGE, _) => state.remote = Some(TxPowerReport::decode(r)),
+                _ => {}
+            },
+            Event::Le(LeEvent::PathLossThreshold { path_loss, zone, .. }) => {
+                let zone = match zone {
+                    0 => PathLossZone::Low,
+                    1 => PathLossZone::Middle,
+                    _ => PathLossZone::High,
+                };
+                state.path_loss = Some(((*path_loss != 0xFF).then_some(*path_loss), zone));
+            }
+            _ => {}
+        }
+        true
+    });
+    if active {
+        conn::waker(index).wake();
+    }
+}
+
+/// Power control on a connection, reporting power changes and path loss
+/// zone crossings as [`PowerEvent`]s.
+pub struct PowerControl<'c> {
+    conn: &'c mut Connection,
+}
+
+impl<'c> PowerControl<'c> {
+    pub fn new(conn: &'c mut Connection) -> Result<Self, Error> {
+        hci::enable_le_events(&[hci::SUBEVT_PATH_LOSS_THRESHOLD, hci::SUBEVT_TRANSMIT_POWER_REPORTING])?;
+        STATES.lock(|states| {
+            states.borrow_mut()[conn.index()] = PowerState {
+                active: true,
+                ..PowerState::new()
+            }
+        });
+        Ok(Self { conn })
+    }
+
+    /// The local transmit power levels on `phy`.
+    pub fn read_local(&self, phy: PowerPhy) -> Result<TxPowerLevel, Error> {
+        let cmd = raw::sdc_hci_cmd_le_enhanced_read_transmit_power_level_t {
+            conn_handle: self.conn.handle(),
+            phy: phy as u8,
+        };
+        let mut ret = unsafe { core::mem::zeroed::<raw::sdc_hci_cmd_le_enhanced_read_transmit_power_level_return_t>() };
+        hci::status(unsafe { raw::sdc_hci_cmd_le_enhanced_read_transmit_power_level(&cmd, &mut ret) })?;
+        Ok(TxPowerLevel {
+            current: ret.current_tx_power_level,
+            max: ret.max_tx_power_level,
+        })
+    }
+
+    /// Read the transmit power level of the peer on `phy`.
+    pub async fn read_remote(&mut self, phy: PowerPhy) -> Result<TxPowerReport, Error> {
+        self.with_state(|state| state.read_remote = None);
+        let cmd = raw::sdc_hci_cmd_le_read_remote_transmit_power_level_t {
+            conn_handle: self.conn.handle(),
+            phy: phy as u8,
+        };
+        hci::status(unsafe { raw::sdc_hci_cmd_le_read_remote_transmit_power_level(&cmd) })?;
+        self.wait(|state| state.read_remote.take()).await?.map_err(Error::Hci)
+    }
+
+    /// Enable or disable reporting of local and remote power level changes.
+    pub fn set_reporting(&mut self, local: bool, remote: bool) -> Result<(), Error> {
+        let cmd = raw::sdc_hci_cmd_le_set_transmit_power_reporting_enable_t {
+            conn_handle: self.conn.handle(),
+            local_enable: local as u8,
+            remote_enable: remote as u8,
+        };
+        let mut ret =
+            unsafe { core::mem::zeroed::<raw::sdc_hci_cmd_le_set_transmit_power_reporting_enable_return_t>() };
+        hci::status(unsafe { raw::sdc_hci_cmd_le_set_transmit_power_reporting_enable(&cmd, &mut ret) })
+    }
+
+    pub fn set_path_loss_params(&mut self, params: &PathLossParams) -> Result<(), Error> {
+        let cmd = raw::sdc_hci_cmd_le_set_path_loss_reporting_params_t {
+            conn_handle: self.conn.handle(),
+            high_threshold: params.high_threshold,
+            high_hysteresis: params.high_hysteresis,
+            low_threshold: params.low_threshold,
+            low_hysteresis: params.low_hysteresis,
+            min_time_spent: params.min_time_spent,
+        };
+        let mut ret = unsafe { core::mem::zeroed::<raw::sdc_hci_cmd_le_set_path_loss_reporting_params_return_t>() };
+        hci::status(unsafe { raw::sdc_hci_cmd_le_set_path_loss_reporting_params(&cmd, &mut ret) })
+    }
+
+    /// Enable or disable path loss reporting, configured with
+    /// [`set_path_loss_params`](Self::set_path_loss_params).
+    pub fn set_path_loss_reporting(&mut self, enable: bool) -> Result<(), Error> {
+        let cmd = raw::sdc_hci_cmd_le_set_path_loss_reporting_enable_t {
+            conn_handle: self.conn.handle(),
+            enable: enable as u8,
+        };
+        let mut ret = unsafe { core::mem::zeroed::<raw::sdc_hci_cmd_le_set_path_loss_reporting_enable_return_t>() };
+        hci::status(unsafe { raw::sdc_hci_cmd_le_set_path_loss_reporting_enable(&cmd, &mut ret) })
+    }
+
+    /// Ask the peer to change its transmit power on `phy` by `delta` dB.
+    pub fn request_remote_adjustment(&mut self, phy: PowerPhy, delta: i8) -> Result<(), Error> {
+        let cmd = raw::sdc_hci_cmd_vs_write_remote_tx_power_t {
+            conn_handle: self.conn.handle(),
+            phy: phy as u8,
+            delta,
+        };
+        hci::status(unsafe { raw::sdc_hci_cmd_vs_write_remote_tx_power(&cmd) })
+    }
+
+    /// Wait for the next power event. Events of the same kind not taken in
+    /// time are replaced by the latest one.
+    pub async fn next(&mut self) -> Result<PowerEvent, Error> {
+        self.wait(|state| {
+            if let Some(report) = state.local.take() {
+                Some(PowerEvent::Local(report))
+            } else if let Some(report) = state.remote.take() {
+                Some(PowerEvent::Remote(report))
+            } else {
+                let (path_loss, zone) = state.path_loss.take()?;
+                Some(PowerEvent::PathLoss { path_loss, zone })
+            }
+        })
+        .await
+    }
+
+    fn with_state<R>(&self, f: impl FnOnce(&mut PowerState) -> R) -> R {
+        STATES.lock(|states| f(&mut states.borrow_mut()[self.conn.index()]))
+    }
+
+    /// Wait until `f` returns a value or the connection is closed.
+    async fn wait<R>(&self, mut f: impl FnMut(&mut PowerState) -> Option<R>) -> Result<R, Error> {
+        poll_fn(|cx| {
+            conn::waker(self.conn.index()).register(cx.waker());
+            if let Some(value) = self.with_state(&mut f) {
+                return Poll::Ready(Ok(value));
+            }
+            match self.conn.is_connected() {
+                true => Poll::Pending,
+                false => Poll::Ready(Err(Error::Disconnected)),
+            }
+        })
+        .await
+    }
+}
+
+impl<'c> Drop for PowerControl<'c> {
+    fn drop(&mut self) {
+        self.with_state(|state| *state = PowerState::new());
+    }
+}
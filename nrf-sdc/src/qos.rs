@@ -0,0 +1,411 @@
+//! Quality of service reports for every connection event, with rolling
+//! per-connection statistics and optional channel classification based on them.
+//!
+//! Requires [`sdc_run`](crate::sdc::sdc_run) to be running.
+use super::conn::{self, Connection, MAX_CONNECTIONS};
+use super::hci::{self, Event, QosConnEventReport, VendorEvent};
+use super::raw;
+use super::Error;
+use core::cell::RefCell;
+use core::pin::Pin;
+use core::sync::atomic::{AtomicBool, Ordering};
+use core::task::{Context, Poll};
+use embassy_sync::blocking_mutex::raw::CriticalSectionRawMutex;
+use embassy_sync::blocking_mutex::CriticalSectionMutex;
+use embassy_sync::channel::Channel;
+use futures_core::Stream;
+
+/// Number of data channels.
+pub const NUM_CHANNELS: usize = 37;
+
+/// Channel counts are halved once a channel has seen more receptions, so the
+/// statistics follow recent conditions.
+const CHANNEL_WINDOW: u16 = 64;
+
+const REPORT_QUEUE_LEN: usize = 8;
+
+/// All data channels in use.
+const ALL_CHANNELS: [u8; 5] = [0xFF, 0xFF, 0xFF, 0xFF, 0x1F];
+
+/// Minimum number of good channels in a channel map.
+const MIN_GOOD_CHANNELS: u32 = 2;
+
+/// Recent receptions on a data channel.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ChannelStats {
+    /// Packets received with a good CRC.
+    pub ok: u16,
+    /// Packets received with a bad CRC, and connection events ending with a
+    /// receive timeout.
+    pub errors: u16,
+}
+
+impl ChannelStats {
+    /// Share of receptions that failed in percent, `None` before any reception.
+    pub fn error_rate(&self) -> Option<u8> {
+        let total = self.ok as u32 + self.errors as u32;
+        (total > 0).then(|| (self.errors as u32 * 100 / total) as u8)
+    }
+
+    fn add(&mut self, ok: u16, errors: u16) {
+        self.ok = self.ok.saturating_add(ok);
+        self.errors = self.errors.saturating_add(errors);
+        if self.ok.saturating_add(self.errors) > CHANNEL_WINDOW {
+            self.ok /= 2;
+            self.errors /= 2;
+        }
+    }
+}
+
+/// Statistics of a connection since the first report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct QosStats {
+    /// Connection events reported.
+    pub events: u32,
+    pub crc_ok: u32,
+    pub crc_errors: u32,
+    pub naks: u32,
+    pub rx_timeouts: u32,
+    /// Recent receptions on each data channel.
+    pub channels: [ChannelStats; NUM_CHANNELS],
+}
+
+impl QosStats {
+    const fn new() -> Self {
+        Self {
+            events: 0,
+            crc_ok: 0,
+            crc_errors: 0,
+            naks: 0,
+            rx_timeouts: 0,
+            channels: [ChannelStats { ok: 0, errors: 0 }; NUM_CHANNELS],
+        }
+    }
+
+    fn add(&mut self, r: &QosConnEventReport) {
+        self.events = self.events.wrapping_add(1);
+        self.crc_ok = self.crc_ok.wrapping_add(r.crc_ok_count as u32);
+        self.crc_errors = self.crc_errors.wrapping_add(r.crc_error_count as u32);
+        self.naks = self.naks.wrapping_add(r.nak_count as u32);
+        self.rx_timeouts = self.rx_timeouts.wrapping_add(r.rx_timeout as u32);
+        if let Some(channel) = self.channels.get_mut(r.channel_index as usize) {
+            channel.add(r.crc_ok_count, r.crc_error_count.saturating_add(r.rx_timeout as u16));
+        }
+    }
+}
+
+/// When channels are classified as bad. Classification applies to all
+/// connections, from the receptions on all of them.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ClassificationParams {
+    /// Error rate in percent above which a channel is bad.
+    pub max_error_rate: u8,
+    /// Receptions needed on a channel before it is classified.
+    pub min_receptions: u16,
+    /// Connection events between two updates of the classification. The
+    /// updates should be at least one second apart, so set this according to
+    /// the connection intervals in use.
+    pub min_events_between_updates: u32,
+    /// Updates a bad channel stays excluded before it is used again with its
+    /// statistics cleared, as excluded channels see no receptions to recover.
+    pub trial_after_updates: u16,
+}
+
+impl Default for ClassificationParams {
+    fn default() -> Self {
+        Self {
+            max_error_rate: 30,
+            min_receptions: 16,
+            min_events_between_updates: 200,
+            trial_after_updates: 30,
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct ConnQos {
+    handle: u16,
+    stats: QosStats,
+}
+
+struct State {
+    enabled: bool,
+    conns: [Option<ConnQos>; MAX_CONNECTIONS],
+    classification: Option<ClassificationParams>,
+    channel_map: [u8; 5],
+    events_since_update: u32,
+    /// Updates each channel has been classified as bad in a row.
+    bad_updates: [u16; NUM_CHANNELS],
+}
+
+static STATE: CriticalSectionMutex<RefCell<State>> = CriticalSectionMutex::new(RefCell::new(State::new()));
+static REPORTS: Channel<CriticalSectionRawMutex, QosConnEventReport, REPORT_QUEUE_LEN> = Channel::new();
+static TAKEN: AtomicBool = AtomicBool::new(false);
+
+pub(crate) fn on_event(event: &Event) {
+    let Event::Vendor(VendorEvent::QosConnEventReport(r)) = event else {
+        return;
+    };
+    let Some(index) = conn::index_of(r.conn_handle) else {
+        return;
+    };
+    let update = STATE.lock(|state| {
+        let mut state = state.borrow_mut();
+        if !state.enabled {
+            return None;
+        }
+        let conn = state.conns[index].get_or_insert(ConnQos {
+            handle: r.conn_handle,
+            stats: QosStats::new(),
+        });
+        // The slot was used by an earlier connection.
+        if conn.handle != r.conn_handle {
+            *conn = ConnQos {
+                handle: r.conn_handle,
+                stats: QosStats::new(),
+            };
+        }
+        conn.stats.add(r);
+        state.classify()
+    });
+    // Dropped when nobody reads them fast enough.
+    let _ = REPORTS.try_send(*r);
+
+    if let Some(map) = update {
+        if let Err(e) = set_channel_map(&map) {
+            warn!("[qos] failed to set channel classification: {:?}", e);
+        }
+    }
+}
+
+impl State {
+    const fn new() -> Self {
+        Self {
+            enabled: false,
+            conns: [None; MAX_CONNECTIONS],
+            classification: None,
+            channel_map: ALL_CHANNELS,
+            events_since_update: 0,
+            bad_updates: [0; NUM_CHANNELS],
+        }
+    }
+
+    /// A new channel map, if it is time to update the classification and it changed.
+    fn classify(&mut self) -> Option<[u8; 5]> {
+        let params = self.classification?;
+        self.events_since_update = self.events_since_update.saturating_add(1);
+        if self.events_since_update < params.min_events_between_updates {
+            return None;
+        }
+        self.events_since_update = 0;
+
+        let mut map = ALL_CHANNELS;
+        for channel in 0..NUM_CHANNELS {
+            if !self.is_bad(channel, &params) {
+                self.bad_updates[channel] = 0;
+                continue;
+            }
+            self.bad_updates[channel] = self.bad_updates[channel].saturating_add(1);
+            if self.bad_updates[channel] > params.trial_after_updates {
+                self.bad_updates[channel] = 0;
+                for conn in self.conns.iter_mut().flatten() {
+                    conn.stats.channels[channel] = ChannelStats::default();
+                }
+                continue;
+            }
+            map[channel / 8] &= !(1 << (channel % 8));
+        }
+        let good: u32 = map.iter().map(|b| b.count_ones()).sum();
+        if good < MIN_GOOD_CHANNELS || map == self.channel_map {
+            return None;
+        }
+        self.channel_map = map;
+        Some(map)
+    }
+
+    /// Whether the receptions on `channel` over all connections make it bad.
+    fn is_bad(&self, channel: usize, params: &ClassificationParams) -> bool {
+        let mut total = ChannelStats::default();
+        for conn in self.conns.iter().flatten() {
+            let c = conn.stats.channels[channel];
+            total.ok = total.ok.saturating_add(c.ok);
+            total.errors = total.errors.saturating_add(c.errors);
+        }
+        let receptions = total.ok.saturating_add(total.errors);
+        receptions >= params.min_receptions && matches!(total.error_rate(), Some(rate) if rate > params.max_error_rate)
+    }
+}
+
+fn set_channel_map(map: &[u8; 5]) -> Result<(), Error> {
+    let params = raw::sdc_hci_cmd_le_set_host_channel_classification_t { channel_map: *map };
+    hci::status(unsafe { raw::sdc_hci_cmd_le_set_host_channel_classification(&params) })
+}
+
+fn set_reports_enabled(enable: bool) -> Result<(), Error> {
+    let params = raw::sdc_hci_cmd_vs_qos_conn_event_report_enable_t { enable: enable as u8 };
+    hci::status(unsafe { raw::sdc_hci_cmd_vs_qos_conn_event_report_enable(&params) })
+}
+
+/// Enables QoS reports while it exists. Only one may exist.
+pub struct Qos {
+    _private: (),
+}
+
+impl Qos {
+    pub fn new() -> Result<Self, Error> {
+        if TAKEN.swap(true, Ordering::AcqRel) {
+            return Err(Error::NotPermitted);
+        }
+        while REPORTS.try_receive().is_ok() {}
+        STATE.lock(|state| {
+            let mut state = state.borrow_mut();
+            state.enabled = true;
+            state.conns = [None; MAX_CONNECTIONS];
+            state.events_since_update = 0;
+            state.bad_updates = [0; NUM_CHANNELS];
+        });
+        if let Err(e) = set_reports_enabled(true) {
+            STATE.lock(|state| state.borrow_mut().enabled = false);
+            TAKEN.store(false, Ordering::Release);
+            return Err(e);
+        }
+        Ok(Self { _private: () })
+    }
+
+    /// Statistics of `conn`, `None` before its first report.
+    pub fn stats(&self, conn: &Connection) -> Option<QosStats> {
+        let index = conn.index();
+        STATE.lock(|state| {
+            state.borrow().conns[index]
+                .as_ref()
+                .filter(|c| c.handle == conn.handle())
+                .map(|c| c.stats)
+        })
+    }
+
+    /// Wait for the next report of any connection. Reports are dropped while
+    /// the queue is full.
+    pub async fn next(&mut self) -> QosConnEventReport {
+        REPORTS.receive().await
+    }
+
+    /// Classify channels from the statistics, or stop and mark all channels
+    /// as good again with `None`.
+    pub fn set_classification(&mut self, params: Option<ClassificationParams>) -> Result<(), Error> {
+        let reset = STATE.lock(|state| {
+            let mut state = state.borrow_mut();
+            state.classification = params;
+            state.events_since_update = 0;
+            state.bad_updates = [0; NUM_CHANNELS];
+            let reset = params.is_none() && state.channel_map != ALL_CHANNELS;
+            if reset {
+                state.channel_map = ALL_CHANNELS;
+            }
+            reset
+        });
+        if reset {
+            set_channel_map(&ALL_CHANNELS)?;
+        }
+        Ok(())
+    }
+
+    /// The channel map last sent to the controller, one bit per data channel.
+    pub fn channel_map(&self) -> [u8; 5] {
+        STATE.lock(|state| state.borrow().channel_map)
+    }
+}
+
+impl Stream for Qos {
+    type Item = QosConnEventReport;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<QosConnEventReport>> {
+        REPORTS.poll_receive(cx).map(Some)
+    }
+}
+
+impl Drop for Qos {
+    fn drop(&mut self) {
+        if let Err(e) = set_reports_enabled(false) {
+            warn!("[qos] failed to disable reports: {:?}", e);
+        }
+        if let Err(e) = self.set_classification(None) {
+            warn!("[qos] failed to reset channel classification: {:?}", e);
+        }
+        STATE.lock(|state| state.borrow_mut().enabled = false);
+        while REPORTS.try_receive().is_ok() {}
+        TAKEN.store(false, Ordering::Release);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PARAMS: ClassificationParams = ClassificationParams {
+        max_error_rate: 30,
+        min_receptions: 16,
+        min_events_between_updates: 2,
+        trial_after_updates: 3,
+    };
+
+    fn state(channels: &[(usize, ChannelStats)]) -> State {
+        let mut stats = QosStats::new();
+        for (channel, c) in channels {
+            stats.channels[*channel] = *c;
+        }
+        let mut state = State::new();
+        state.classification = Some(PARAMS);
+        state.conns[0] = Some(ConnQos { handle: 0, stats });
+        state
+    }
+
+    fn update(state: &mut State) -> Option<[u8; 5]> {
+        (0..PARAMS.min_events_between_updates).find_map(|_| state.classify())
+    }
+
+    #[test]
+    fn excludes_bad_channels() {
+        let mut state = state(&[
+            (3, ChannelStats { ok: 10, errors: 10 }),
+            // Too few receptions to tell.
+            (4, ChannelStats { ok: 0, errors: 15 }),
+            (36, ChannelStats { ok: 40, errors: 10 }),
+        ]);
+        assert_eq!(state.classify(), None);
+        assert_eq!(state.classify(), Some([0xF7, 0xFF, 0xFF, 0xFF, 0x1F]));
+        assert_eq!(state.channel_map, [0xF7, 0xFF, 0xFF, 0xFF, 0x1F]);
+    }
+
+    #[test]
+    fn counts_events_from_each_update() {
+        let mut state = state(&[]);
+        assert_eq!(update(&mut state), None);
+        assert_eq!(state.events_since_update, 0);
+        state.conns[0].as_mut().unwrap().stats.channels[0] = ChannelStats { ok: 0, errors: 20 };
+        assert_eq!(state.classify(), None);
+        assert_eq!(state.classify(), Some([0xFE, 0xFF, 0xFF, 0xFF, 0x1F]));
+    }
+
+    #[test]
+    fn retries_excluded_channels() {
+        let mut state = state(&[(8, ChannelStats { ok: 0, errors: 20 })]);
+        assert_eq!(update(&mut state), Some([0xFF, 0xFE, 0xFF, 0xFF, 0x1F]));
+        for _ in 1..PARAMS.trial_after_updates {
+            assert_eq!(update(&mut state), None);
+        }
+        assert_eq!(update(&mut state), Some(ALL_CHANNELS));
+        assert_eq!(state.conns[0].unwrap().stats.channels[8], ChannelStats::default());
+    }
+
+    #[test]
+    fn keeps_min_good_channels() {
+        let bad = ChannelStats { ok: 0, errors: 20 };
+        let channels: [_; NUM_CHANNELS - 1] = core::array::from_fn(|channel| (channel, bad));
+        let mut state = state(&channels);
+        assert_eq!(update(&mut state), None);
+        assert_eq!(state.channel_map, ALL_CHANNELS);
+    }
+}
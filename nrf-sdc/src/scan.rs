@@ -0,0 +1,499 @@
+//! Scanning for legacy and extended advertising on the 1M and Coded PHYs.
+//!
+//! Only the extended scanning commands are used, as the controller rejects
+//! mixing them with the legacy ones once extended advertising is in use.
+//! Requires [`sdc_run`](crate::sdc::sdc_run) to be running.
+use super::adv_data::{self, AdStructures};
+use super::hci::{self, AddrKind, Address, Event, ExtAdvReport, LeEvent, Phy};
+use super::raw;
+use super::Error;
+use core::cell::RefCell;
+use core::pin::Pin;
+use core::sync::atomic::{AtomicBool, Ordering};
+use core::task::{Context, Poll};
+use embassy_sync::blocking_mutex::raw::CriticalSectionRawMutex;
+use embassy_sync::blocking_mutex::CriticalSectionMutex;
+use embassy_sync::channel::Channel;
+use futures_core::Stream;
+
+/// Maximum length of a reassembled advertising payload.
+pub const MAX_REPORT_LEN: usize = adv_data::EXTENDED_MAX_LEN;
+
+// Kept low, each queued report holds a full size payload buffer.
+const REPORT_QUEUE_LEN: usize = 2;
+
+const DATA_STATUS_COMPLETE: u16 = 0;
+const DATA_STATUS_INCOMPLETE: u16 = 1;
+
+// Advertisers whose fragments are skipped after their chain was interrupted.
+const DISCARD_LEN: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ScanFilterPolicy {
+    /// Accept all advertising, except directed advertising not addressed to us.
+    Unfiltered = 0,
+    /// Only accept advertising from devices in the filter accept list.
+    FilterAcceptList = 1,
+    /// Like `Unfiltered`, also accepting directed advertising to resolvable addresses.
+    UnfilteredResolvable = 2,
+    /// Like `FilterAcceptList`, also accepting directed advertising to resolvable addresses.
+    FilterAcceptListResolvable = 3,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ScanParams {
+    /// Send scan requests to scannable advertisers.
+    pub active: bool,
+    /// Scan interval in units of 0.625 ms.
+    pub interval: u16,
+    /// Scan window in units of 0.625 ms.
+    pub window: u16,
+    pub phy_1m: bool,
+    pub phy_coded: bool,
+    pub own_addr_kind: AddrKind,
+    pub filter_policy: ScanFilterPolicy,
+    /// Let the controller drop duplicate reports.
+    pub filter_duplicates: bool,
+}
+
+impl Default for ScanParams {
+    fn default() -> Self {
+        Self {
+            active: false,
+            interval: 160,
+            window: 160,
+            phy_1m: true,
+            phy_coded: false,
+            own_addr_kind: AddrKind::Public,
+            filter_policy: ScanFilterPolicy::Unfiltered,
+            filter_duplicates: false,
+        }
+    }
+}
+
+/// A complete advertising report, with the data of all fragments reassembled.
+#[derive(Clone)]
+pub struct Report {
+    /// Event type bits as reported by the controller.
+    pub event_type: u16,
+    /// Advertiser address, `None` for anonymous advertising.
+    pub addr: Option<Address>,
+    pub primary_phy: Phy,
+    /// PHY of the auxiliary packets, `None` for legacy advertising.
+    pub secondary_phy: Option<Phy>,
+    /// Advertising SID, `None` if not present.
+    pub sid: Option<u8>,
+    /// TX power in dBm, `None` if not available.
+    pub tx_power: Option<i8>,
+    /// RSSI in dBm, `None` if not available.
+    pub rssi: Option<i8>,
+    /// Periodic advertising interval in units of 1.25 ms, zero if none.
+    pub periodic_adv_interval: u16,
+    /// Target address of directed advertising.
+    pub direct_addr: Option<Address>,
+    /// The data is incomplete, because the controller stopped receiving
+    /// fragments, or because they were interleaved with those of another
+    /// advertiser.
+    pub truncated: bool,
+    len: usize,
+    data: [u8; MAX_REPORT_LEN],
+}
+
+impl Report {
+    const fn new() -> Self {
+        Self {
+            event_type: 0,
+            addr: None,
+            primary_phy: Phy::Le1M,
+            secondary_phy: None,
+            sid: None,
+            tx_power: None,
+            rssi: None,
+            periodic_adv_interval: 0,
+            direct_addr: None,
+            truncated: false,
+            len: 0,
+            data: [0; MAX_REPORT_LEN],
+        }
+    }
+
+    fn start(&mut self, r: &ExtAdvReport) {
+        self.event_type = r.event_type;
+        self.addr = address(r.addr_kind, r.addr);
+        self.primary_phy = Phy::from_u8(r.primary_phy).unwrap_or(Phy::Le1M);
+        self.secondary_phy = Phy::from_u8(r.secondary_phy);
+        self.sid = (r.sid != 0xFF).then_some(r.sid);
+        self.tx_power = (r.tx_power != 0x7F).then_some(r.tx_power);
+        self.rssi = (r.rssi != 0x7F).then_some(r.rssi);
+        self.periodic_adv_interval = r.periodic_adv_interval;
+        self.direct_addr = (r.event_type & 0x04 != 0)
+            .then(|| address(r.direct_addr_kind, r.direct_addr))
+            .flatten();
+        self.truncated = false;
+        self.len = 0;
+    }
+
+    fn append(&mut self, data: &[u8]) {
+        let len = core::cmp::min(data.len(), MAX_REPORT_LEN - self.len);
+        self.data[self.len..self.len + len].copy_from_slice(&data[..len]);
+        self.len += len;
+        self.truncated |= len < data.len();
+    }
+
+    fn source(&self) -> Source {
+        Source {
+            addr: self.addr,
+            sid: self.sid,
+        }
+    }
+
+    pub fn is_connectable(&self) -> bool {
+        self.event_type & 0x01 != 0
+    }
+
+    pub fn is_scannable(&self) -> bool {
+        self.event_type & 0x02 != 0
+    }
+
+    pub fn is_scan_response(&self) -> bool {
+        self.event_type & 0x08 != 0
+    }
+
+    pub fn is_legacy(&self) -> bool {
+        self.event_type & 0x10 != 0
+    }
+
+    /// The advertising or scan response data.
+    pub fn data(&self) -> &[u8] {
+        &self.data[..self.len]
+    }
+
+    /// The AD structures in the data.
+    pub fn ad_structures(&self) -> AdStructures<'_> {
+        adv_data::parse(self.data())
+    }
+}
+
+fn address(kind: u8, addr: [u8; 6]) -> Option<Address> {
+    Some(Address {
+        kind: AddrKind::from_u8(kind)?,
+        addr,
+    })
+}
+
+/// The advertiser and advertising set a fragment belongs to.
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct Source {
+    addr: Option<Address>,
+    sid: Option<u8>,
+}
+
+impl Source {
+    fn of(r: &ExtAdvReport) -> Self {
+        Self {
+            addr: address(r.addr_kind, r.addr),
+            sid: (r.sid != 0xFF).then_some(r.sid),
+        }
+    }
+}
+
+struct ScanState {
+    scanning: bool,
+    reassembling: bool,
+    report: Report,
+    /// Advertisers whose remaining fragments are dropped, up to their last one.
+    discarding: [Option<Source>; DISCARD_LEN],
+}
+
+impl ScanState {
+    const fn new() -> Self {
+        Self {
+            scanning: false,
+            reassembling: false,
+            report: Report::new(),
+            discarding: [None; DISCARD_LEN],
+        }
+    }
+
+    fn discard(&mut self, source: Source) {
+        // When all slots are taken, the oldest chain is given up on.
+        let slot = self.discarding.iter().position(Option::is_none).unwrap_or(0);
+        self.discarding[slot] = Some(source);
+    }
+
+    /// Adds a fragment to the report being reassembled, passing each report
+    /// it completes or cuts short to `deliver`.
+    fn on_fragment(&mut self, r: &ExtAdvReport, mut deliver: impl FnMut(&Report)) {
+        let source = Source::of(r);
+        let status = (r.event_type >> 5) & 0x03;
+        if let Some(slot) = self.discarding.iter().position(|d| *d == Some(source)) {
+            if status != DATA_STATUS_INCOMPLETE {
+                self.discarding[slot] = None;
+            }
+            return;
+        }
+        if self.reassembling && self.report.source() != source {
+            // Send what there is, and skip the rest of the chain rather
+            // than reporting its tail as a report of its own.
+            debug!("[scan] interleaved fragments, truncating report");
+            self.report.truncated = true;
+            deliver(&self.report);
+            let interrupted = self.report.source();
+            self.discard(interrupted);
+            self.reassembling = false;
+        }
+        if !self.reassembling {
+            self.report.start(r);
+        }
+        self.report.append(r.data);
+
+        match status {
+            DATA_STATUS_INCOMPLETE => self.reassembling = true,
+            status => {
+                self.report.truncated |= status != DATA_STATUS_COMPLETE;
+                self.reassembling = false;
+                deliver(&self.report);
+            }
+        }
+    }
+}
+
+static STATE: CriticalSectionMutex<RefCell<ScanState>> = CriticalSectionMutex::new(RefCell::new(ScanState::new()));
+static REPORTS: Channel<CriticalSectionRawMutex, Report, REPORT_QUEUE_LEN> = Channel::new();
+static TAKEN: AtomicBool = AtomicBool::new(false);
+
+pub(crate) fn on_event(event: &Event) {
+    let Event::Le(LeEvent::ExtAdvReport(reports)) = event else {
+        return;
+    };
+    STATE.lock(|state| {
+        let mut state = state.borrow_mut();
+        if !state.scanning {
+            return;
+        }
+        for r in reports.iter() {
+            state.on_fragment(&r, |report| {
+                if REPORTS.try_send(report.clone()).is_err() {
+                    debug!("[scan] report queue full");
+                }
+            });
+        }
+    });
+}
+
+/// Scans using the extended scanning commands. Only one scanner may exist.
+pub struct Scanner {
+    _private: (),
+}
+
+impl Scanner {
+    pub fn new() -> Result<Self, Error> {
+        if TAKEN.swap(true, Ordering::AcqRel) {
+            return Err(Error::NotPermitted);
+        }
+        if let Err(e) = hci::enable_le_events(&[hci::SUBEVT_EXT_ADV_REPORT]) {
+            TAKEN.store(false, Ordering::Release);
+            return Err(e);
+        }
+        Ok(Self { _private: () })
+    }
+
+    /// Start scanning. Scanning stops when the returned [`Scan`] is dropped.
+    pub fn scan(&mut self, params: &ScanParams) -> Result<Scan<'_>, Error> {
+        if !params.phy_1m && !params.phy_coded {
+            return Err(Error::InvalidArg);
+        }
+
+        // Own address type, filter policy and PHYs, followed by the
+        // parameters for each PHY.
+        let mut buf = [0; 3 + 2 * 5];
+        buf[0] = params.own_addr_kind as u8;
+        buf[1] = params.filter_policy as u8;
+        let mut len = 3;
+        for (enabled, bit) in [(params.phy_1m, 0x01), (params.phy_coded, 0x04)] {
+            if enabled {
+                buf[2] |= bit;
+                buf[len] = params.active as u8;
+                buf[len + 1..len + 3].copy_from_slice(&params.interval.to_le_bytes());
+                buf[len + 3..len + 5].copy_from_slice(&params.window.to_le_bytes());
+                len += 5;
+            }
+        }
+        hci::status(unsafe { raw::sdc_hci_cmd_le_set_ext_scan_params(buf.as_ptr() as *const _) })?;
+
+        while REPORTS.try_receive().is_ok() {}
+        STATE.lock(|state| {
+            let mut state = state.borrow_mut();
+            state.scanning = true;
+            state.reassembling = false;
+            state.discarding = [None; DISCARD_LEN];
+        });
+
+        let ret = set_enable(true, params.filter_duplicates);
+        if let Err(e) = ret {
+            STATE.lock(|state| state.borrow_mut().scanning = false);
+            return Err(e);
+        }
+        Ok(Scan { _scanner: self })
+    }
+}
+
+impl Drop for Scanner {
+    fn drop(&mut self) {
+        TAKEN.store(false, Ordering::Release);
+    }
+}
+
+/// An ongoing scan, yielding the received reports.
+pub struct Scan<'a> {
+    _scanner: &'a mut Scanner,
+}
+
+impl<'a> Scan<'a> {
+    /// Wait for the next report.
+    pub async fn next(&mut self) -> Report {
+        REPORTS.receive().await
+    }
+}
+
+impl<'a> Stream for Scan<'a> {
+    type Item = Report;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Report>> {
+        REPORTS.poll_receive(cx).map(Some)
+    }
+}
+
+impl<'a> Drop for Scan<'a> {
+    fn drop(&mut self) {
+        if let Err(e) = set_enable(false, false) {
+            warn!("[scan] failed to stop scanning: {:?}", e);
+        }
+        STATE.lock(|state| state.borrow_mut().scanning = false);
+        while REPORTS.try_receive().is_ok() {}
+    }
+}
+
+fn set_enable(enable: bool, filter_duplicates: bool) -> Result<(), Error> {
+    let params = raw::sdc_hci_cmd_le_set_ext_scan_enable_t {
+        enable: enable as u8,
+        filter_duplicates: filter_duplicates as u8,
+        duration: 0,
+        period: 0,
+    };
+    hci::status(unsafe { raw::sdc_hci_cmd_le_set_ext_scan_enable(&params) })
+}
+
+/// Add a device to the filter accept list used by the scanner and initiator.
+pub fn accept_list_add(addr: &Address) -> Result<(), Error> {
+    let params = raw::sdc_hci_cmd_le_add_device_to_filter_accept_list_t {
+        address_type: addr.kind as u8,
+        address: addr.addr,
+    };
+    hci::status(unsafe { raw::sdc_hci_cmd_le_add_device_to_filter_accept_list(&params) })
+}
+
+pub fn accept_list_remove(addr: &Address) -> Result<(), Error> {
+    let params = raw::sdc_hci_cmd_le_remove_device_from_filter_accept_list_t {
+        address_type: addr.kind as u8,
+        address: addr.addr,
+    };
+    hci::status(unsafe { raw::sdc_hci_cmd_le_remove_device_from_filter_accept_list(&params) })
+}
+
+pub fn accept_list_clear() -> Result<(), Error> {
+    hci::status(unsafe { raw::sdc_hci_cmd_le_clear_filter_accept_list() })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COMPLETE: u16 = DATA_STATUS_COMPLETE << 5;
+    const INCOMPLETE: u16 = DATA_STATUS_INCOMPLETE << 5;
+    const TRUNCATED: u16 = 2 << 5;
+
+    fn fragment(addr: u8, event_type: u16, data: &[u8]) -> ExtAdvReport<'_> {
+        ExtAdvReport {
+            event_type,
+            addr_kind: 0,
+            addr: [addr; 6],
+            primary_phy: 1,
+            secondary_phy: 1,
+            sid: 0,
+            tx_power: 0x7F,
+            rssi: -50,
+            periodic_adv_interval: 0,
+            direct_addr_kind: 0,
+            direct_addr: [0; 6],
+            data,
+        }
+    }
+
+    /// Feeds the fragments in order, returning the data and truncation of
+    /// each delivered report.
+    fn reassemble(fragments: &[ExtAdvReport]) -> Vec<(Option<Address>, Vec<u8>, bool)> {
+        let mut state = ScanState::new();
+        let mut reports = Vec::new();
+        for r in fragments {
+            state.on_fragment(r, |report| {
+                reports.push((report.addr, report.data().to_vec(), report.truncated))
+            });
+        }
+        reports
+    }
+
+    fn addr(addr: u8) -> Option<Address> {
+        address(0, [addr; 6])
+    }
+
+    #[test]
+    fn reassembles_a_chain() {
+        let reports = reassemble(&[
+            fragment(1, INCOMPLETE, &[1, 2]),
+            fragment(1, INCOMPLETE, &[3]),
+            fragment(1, COMPLETE, &[4, 5]),
+        ]);
+        assert_eq!(reports, [(addr(1), vec![1, 2, 3, 4, 5], false)]);
+    }
+
+    #[test]
+    fn marks_a_chain_cut_short_by_the_controller() {
+        let reports = reassemble(&[fragment(1, INCOMPLETE, &[1, 2]), fragment(1, TRUNCATED, &[3])]);
+        assert_eq!(reports, [(addr(1), vec![1, 2, 3], true)]);
+    }
+
+    #[test]
+    fn skips_the_rest_of_an_interleaved_chain() {
+        let reports = reassemble(&[
+            fragment(1, INCOMPLETE, &[1]),
+            fragment(2, INCOMPLETE, &[10]),
+            fragment(1, INCOMPLETE, &[2]),
+            fragment(2, COMPLETE, &[11]),
+            fragment(1, COMPLETE, &[3]),
+            fragment(1, COMPLETE, &[4]),
+        ]);
+        assert_eq!(
+            reports,
+            [
+                (addr(1), vec![1], true),
+                (addr(2), vec![10, 11], false),
+                (addr(1), vec![4], false),
+            ]
+        );
+    }
+
+    #[test]
+    fn truncates_data_beyond_the_buffer() {
+        let data = [0xAA; MAX_REPORT_LEN - 10];
+        let reports = reassemble(&[fragment(1, INCOMPLETE, &data), fragment(1, COMPLETE, &[0xBB; 20])]);
+        assert_eq!(reports.len(), 1);
+        let (_, data, truncated) = &reports[0];
+        assert_eq!(data.len(), MAX_REPORT_LEN);
+        assert!(data[..MAX_REPORT_LEN - 10].iter().all(|b| *b == 0xAA));
+        assert!(data[MAX_REPORT_LEN - 10..].iter().all(|b| *b == 0xBB));
+        assert!(*truncated);
+    }
+}
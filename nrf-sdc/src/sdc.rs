@@ -1,4 +1,7 @@
+use super::hci;
+use super::llpm;
 use super::raw;
+use super::tx_power::{self, TxPower, TxPowerEnvelope};
 use super::Error;
 use core::cell::RefCell;
 use core::future::poll_fn;
@@ -7,8 +10,56 @@ use embassy_sync::blocking_mutex::CriticalSectionMutex;
 use embassy_sync::waitqueue::AtomicWaker;
 use rand_chacha::rand_core::{RngCore as _, SeedableRng as _};
 
+/// Controller configuration. [`Config::default`] takes the defaults of the
+/// SoftDevice Controller, except for the seed, which must be set.
 pub struct Config {
+    /// Seed of the random number generator, taken from a hardware RNG.
     pub seed: [u8; 32],
+    /// Number of advertising sets the controller can run concurrently.
+    /// Defaults to [`raw::SDC_DEFAULT_ADV_COUNT`].
+    #[cfg(any(feature = "peripheral", feature = "multirole"))]
+    pub adv_count: u8,
+    /// Maximum advertising or scan response data length per advertising set.
+    /// Values above 31 are only useful with extended advertising. Defaults to
+    /// [`raw::SDC_DEFAULT_ADV_BUF_SIZE`].
+    #[cfg(any(feature = "peripheral", feature = "multirole"))]
+    pub max_adv_data: u16,
+    /// Number of advertising sets that can do periodic advertising, at most `adv_count`.
+    /// Defaults to [`raw::SDC_DEFAULT_PERIODIC_ADV_COUNT`].
+    #[cfg(feature = "multirole")]
+    pub periodic_adv_count: u8,
+    /// Number of periodic advertising trains that can be synchronized to concurrently.
+    /// Defaults to [`raw::SDC_DEFAULT_PERIODIC_SYNC_COUNT`].
+    #[cfg(feature = "multirole")]
+    pub periodic_sync_count: u8,
+    /// Maximum connection event length in microseconds, and the event length
+    /// of new connections until changed with [`llpm::set_event_length`].
+    /// Defaults to [`raw::SDC_DEFAULT_EVENT_LENGTH_US`].
+    pub event_length_us: u32,
+    /// TX power used where no override is set with [`tx_power::set_tx_power`].
+    /// Defaults to 0 dBm.
+    pub tx_power: TxPower,
+    /// Per-channel TX power limits, at most one per PHY. Defaults to none.
+    pub tx_power_envelopes: &'static [TxPowerEnvelope],
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            seed: [0; 32],
+            #[cfg(any(feature = "peripheral", feature = "multirole"))]
+            adv_count: raw::SDC_DEFAULT_ADV_COUNT as u8,
+            #[cfg(any(feature = "peripheral", feature = "multirole"))]
+            max_adv_data: raw::SDC_DEFAULT_ADV_BUF_SIZE as u16,
+            #[cfg(feature = "multirole")]
+            periodic_adv_count: raw::SDC_DEFAULT_PERIODIC_ADV_COUNT as u8,
+            #[cfg(feature = "multirole")]
+            periodic_sync_count: raw::SDC_DEFAULT_PERIODIC_SYNC_COUNT as u8,
+            event_length_us: raw::SDC_DEFAULT_EVENT_LENGTH_US,
+            tx_power: TxPower::ZerodBm,
+            tx_power_envelopes: &[],
+        }
+    }
 }
 
 static RNG: CriticalSectionMutex<RefCell<Option<rand_chacha::ChaCha12Rng>>> =
@@ -40,59 +91,231 @@ pub fn sdc_init(config: Config) -> Result<(), Error> {
         return Err(ret.into());
     }
 
-    let ret = unsafe { raw::sdc_support_adv() };
+    let ret = unsafe { raw::sdc_support_le_2m_phy() };
     if ret != 0 {
         return Err(ret.into());
     }
 
-    let ret = unsafe { raw::sdc_support_peripheral() };
+    let ret = unsafe { raw::sdc_support_le_coded_phy() };
     if ret != 0 {
         return Err(ret.into());
     }
 
-    let ret = unsafe { raw::sdc_support_central() };
-    if ret != 0 {
-        return Err(ret.into());
+    // Peripheral and advertiser.
+    #[cfg(any(feature = "peripheral", feature = "multirole"))]
+    {
+        let ret = unsafe { raw::sdc_support_ext_adv() };
+        if ret != 0 {
+            return Err(ret.into());
+        }
+
+        let ret = unsafe { raw::sdc_support_peripheral() };
+        if ret != 0 {
+            return Err(ret.into());
+        }
+
+        let ret = unsafe { raw::sdc_support_le_power_control_peripheral() };
+        if ret != 0 {
+            return Err(ret.into());
+        }
+
+        let ret = unsafe { raw::sdc_support_le_conn_cte_rsp_peripheral() };
+        if ret != 0 {
+            return Err(ret.into());
+        }
+
+        let ret = unsafe { raw::sdc_support_dle_peripheral() };
+        if ret != 0 {
+            return Err(ret.into());
+        }
+
+        let ret = unsafe { raw::sdc_support_phy_update_peripheral() };
+        if ret != 0 {
+            return Err(ret.into());
+        }
     }
 
-    let ret = unsafe {
-        raw::sdc_cfg_set(
-            raw::SDC_DEFAULT_RESOURCE_CFG_TAG as u8,
-            raw::sdc_cfg_type_SDC_CFG_TYPE_ADV_COUNT as u8,
-            &raw::sdc_cfg_t {
-                adv_count: raw::sdc_cfg_role_count_t { count: 1 },
-            },
-        )
-    };
-    if ret < 0 {
-        return Err(ret.into());
+    // Central and scanner.
+    #[cfg(any(feature = "central", feature = "multirole"))]
+    {
+        // Also covers the extended scanner, which cannot be enabled on its own
+        // together with the central role.
+        let ret = unsafe { raw::sdc_support_ext_central() };
+        if ret != 0 {
+            return Err(ret.into());
+        }
+
+        let ret = unsafe { raw::sdc_support_le_power_control_central() };
+        if ret != 0 {
+            return Err(ret.into());
+        }
+
+        let ret = unsafe { raw::sdc_support_le_conn_cte_rsp_central() };
+        if ret != 0 {
+            return Err(ret.into());
+        }
+
+        let ret = unsafe { raw::sdc_support_dle_central() };
+        if ret != 0 {
+            return Err(ret.into());
+        }
+
+        let ret = unsafe { raw::sdc_support_phy_update_central() };
+        if ret != 0 {
+            return Err(ret.into());
+        }
     }
 
-    let ret = unsafe {
-        raw::sdc_cfg_set(
-            raw::SDC_DEFAULT_RESOURCE_CFG_TAG as u8,
-            raw::sdc_cfg_type_SDC_CFG_TYPE_PERIPHERAL_COUNT as u8,
-            &raw::sdc_cfg_t {
-                peripheral_count: raw::sdc_cfg_role_count_t { count: 1 },
-            },
-        )
-    };
-    if ret < 0 {
-        return Err(ret.into());
+    // Periodic advertising, sync and sync transfer.
+    #[cfg(feature = "multirole")]
+    {
+        let ret = unsafe { raw::sdc_support_le_periodic_adv() };
+        if ret != 0 {
+            return Err(ret.into());
+        }
+
+        let ret = unsafe { raw::sdc_support_le_periodic_sync() };
+        if ret != 0 {
+            return Err(ret.into());
+        }
+
+        let ret = unsafe { raw::sdc_support_periodic_adv_sync_transfer_sender_central() };
+        if ret != 0 {
+            return Err(ret.into());
+        }
+
+        let ret = unsafe { raw::sdc_support_periodic_adv_sync_transfer_sender_peripheral() };
+        if ret != 0 {
+            return Err(ret.into());
+        }
+
+        let ret = unsafe { raw::sdc_support_periodic_adv_sync_transfer_receiver_central() };
+        if ret != 0 {
+            return Err(ret.into());
+        }
+
+        let ret = unsafe { raw::sdc_support_periodic_adv_sync_transfer_receiver_peripheral() };
+        if ret != 0 {
+            return Err(ret.into());
+        }
+    }
+
+    #[cfg(any(feature = "peripheral", feature = "multirole"))]
+    {
+        let ret = unsafe {
+            raw::sdc_cfg_set(
+                raw::SDC_DEFAULT_RESOURCE_CFG_TAG as u8,
+                raw::sdc_cfg_type_SDC_CFG_TYPE_ADV_COUNT as u8,
+                &raw::sdc_cfg_t {
+                    adv_count: raw::sdc_cfg_role_count_t {
+                        count: config.adv_count,
+                    },
+                },
+            )
+        };
+        if ret < 0 {
+            return Err(ret.into());
+        }
+
+        let ret = unsafe {
+            raw::sdc_cfg_set(
+                raw::SDC_DEFAULT_RESOURCE_CFG_TAG as u8,
+                raw::sdc_cfg_type_SDC_CFG_TYPE_ADV_BUFFER_CFG as u8,
+                &raw::sdc_cfg_t {
+                    adv_buffer_cfg: raw::sdc_cfg_adv_buffer_cfg_t {
+                        max_adv_data: config.max_adv_data,
+                    },
+                },
+            )
+        };
+        if ret < 0 {
+            return Err(ret.into());
+        }
+    }
+
+    #[cfg(feature = "multirole")]
+    {
+        let ret = unsafe {
+            raw::sdc_cfg_set(
+                raw::SDC_DEFAULT_RESOURCE_CFG_TAG as u8,
+                raw::sdc_cfg_type_SDC_CFG_TYPE_PERIODIC_ADV_COUNT as u8,
+                &raw::sdc_cfg_t {
+                    periodic_adv_count: raw::sdc_cfg_role_count_t {
+                        count: config.periodic_adv_count,
+                    },
+                },
+            )
+        };
+        if ret < 0 {
+            return Err(ret.into());
+        }
+
+        let ret = unsafe {
+            raw::sdc_cfg_set(
+                raw::SDC_DEFAULT_RESOURCE_CFG_TAG as u8,
+                raw::sdc_cfg_type_SDC_CFG_TYPE_PERIODIC_SYNC_COUNT as u8,
+                &raw::sdc_cfg_t {
+                    periodic_sync_count: raw::sdc_cfg_role_count_t {
+                        count: config.periodic_sync_count,
+                    },
+                },
+            )
+        };
+        if ret < 0 {
+            return Err(ret.into());
+        }
     }
 
     let ret = unsafe {
         raw::sdc_cfg_set(
             raw::SDC_DEFAULT_RESOURCE_CFG_TAG as u8,
-            raw::sdc_cfg_type_SDC_CFG_TYPE_CENTRAL_COUNT as u8,
+            raw::sdc_cfg_type_SDC_CFG_TYPE_EVENT_LENGTH as u8,
             &raw::sdc_cfg_t {
-                central_count: raw::sdc_cfg_role_count_t { count: 1 },
+                event_length: raw::sdc_cfg_event_length_t {
+                    event_length_us: config.event_length_us,
+                },
             },
         )
     };
     if ret < 0 {
         return Err(ret.into());
     }
+    llpm::init(config.event_length_us);
+
+    #[cfg(any(feature = "peripheral", feature = "multirole"))]
+    {
+        let ret = unsafe {
+            raw::sdc_cfg_set(
+                raw::SDC_DEFAULT_RESOURCE_CFG_TAG as u8,
+                raw::sdc_cfg_type_SDC_CFG_TYPE_PERIPHERAL_COUNT as u8,
+                &raw::sdc_cfg_t {
+                    peripheral_count: raw::sdc_cfg_role_count_t { count: 1 },
+                },
+            )
+        };
+        if ret < 0 {
+            return Err(ret.into());
+        }
+    }
+
+    #[cfg(any(feature = "central", feature = "multirole"))]
+    {
+        let ret = unsafe {
+            raw::sdc_cfg_set(
+                raw::SDC_DEFAULT_RESOURCE_CFG_TAG as u8,
+                raw::sdc_cfg_type_SDC_CFG_TYPE_CENTRAL_COUNT as u8,
+                &raw::sdc_cfg_t {
+                    central_count: raw::sdc_cfg_role_count_t { count: 1 },
+                },
+            )
+        };
+        if ret < 0 {
+            return Err(ret.into());
+        }
+    }
+
+    tx_power::set_default(config.tx_power)?;
+    tx_power::set_envelopes(config.tx_power_envelopes)?;
 
     let wanted_memory = unsafe {
         raw::sdc_cfg_set(
@@ -157,6 +380,27 @@ pub async fn sdc_hci_get(data: &mut [u8]) -> Result<usize, Error> {
     .await
 }
 
+/// Receive events from the controller and dispatch them to the high level APIs,
+/// such as the [`Advertiser`](crate::adv::Advertiser) and [`Scanner`](crate::scan::Scanner).
+///
+/// Events can only be received once, so this must not be combined with reading
+/// them through [`sdc_hci_get`] for an external host.
+pub async fn sdc_run() {
+    let mut buf = [0; raw::HCI_MSG_BUFFER_MAX_SIZE as usize];
+    loop {
+        match sdc_hci_get(&mut buf).await {
+            Ok(_) if buf[0] == raw::sdc_hci_msg_type_t_SDC_HCI_MSG_TYPE_EVT as u8 => {
+                match hci::Event::decode(&buf[1..]) {
+                    Some(event) => hci::dispatch(&event),
+                    None => warn!("[sdc] malformed event"),
+                }
+            }
+            Ok(_) => debug!("[sdc] dropping data packet"),
+            Err(e) => warn!("[sdc] hci get failed: {:?}", e),
+        }
+    }
+}
+
 static SDC_WAKER: AtomicWaker = AtomicWaker::new();
 unsafe extern "C" fn sdc_callback() {
     info!("[sdc] hci event!");
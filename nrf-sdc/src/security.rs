@@ -0,0 +1,105 @@
+//! Link encryption. Pairing and bonding are left to the host, which hands
+//! the resulting keys to the controller through this module.
+//!
+//! As central, encryption is started with
+//! [`Connection::encrypt`](crate::conn::Connection::encrypt). As peripheral,
+//! the controller asks for the key when the central starts encryption, which
+//! is answered from the [`KeyStore`] registered with [`set_key_store`].
+#[cfg(any(feature = "peripheral", feature = "multirole"))]
+use super::conn;
+use super::hci;
+#[cfg(any(feature = "peripheral", feature = "multirole"))]
+use super::hci::{Address, Event, LeEvent};
+use super::raw;
+#[cfg(any(feature = "central", feature = "multirole"))]
+use super::Error;
+#[cfg(any(feature = "peripheral", feature = "multirole"))]
+use core::cell::Cell;
+#[cfg(any(feature = "peripheral", feature = "multirole"))]
+use embassy_sync::blocking_mutex::CriticalSectionMutex;
+
+/// A long-term key with the values identifying it.
+///
+/// Keys generated by LE Secure Connections use zero for `rand` and `ediv`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct LongTermKey {
+    pub key: [u8; 16],
+    pub rand: [u8; 8],
+    pub ediv: u16,
+}
+
+/// Encryption state of a connection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Encryption {
+    Off,
+    /// Encrypted with a key of `key_size` octets.
+    On {
+        key_size: u8,
+    },
+}
+
+/// Looks up the long-term key requested by the controller in the peripheral role.
+///
+/// Called from [`sdc_run`](crate::sdc::sdc_run), so lookups must not block.
+#[cfg(any(feature = "peripheral", feature = "multirole"))]
+pub trait KeyStore: Sync {
+    /// The key distributed to or generated with `peer`, matching `ediv` and
+    /// `rand`, or `None` to reject the encryption request.
+    fn long_term_key(&self, peer: &Address, ediv: u16, rand: &[u8; 8]) -> Option<[u8; 16]>;
+}
+
+#[cfg(any(feature = "peripheral", feature = "multirole"))]
+static KEY_STORE: CriticalSectionMutex<Cell<Option<&'static dyn KeyStore>>> =
+    CriticalSectionMutex::new(Cell::new(None));
+
+/// Register the key store answering long-term key requests, replacing the
+/// previous one. Without a key store all requests are rejected.
+#[cfg(any(feature = "peripheral", feature = "multirole"))]
+pub fn set_key_store(store: Option<&'static dyn KeyStore>) {
+    KEY_STORE.lock(|s| s.set(store));
+}
+
+#[cfg(any(feature = "peripheral", feature = "multirole"))]
+pub(crate) fn on_event(event: &Event) {
+    let Event::Le(LeEvent::LongTermKeyRequest(req)) = event else {
+        return;
+    };
+    let key = KEY_STORE
+        .lock(|s| s.get())
+        .zip(conn::peer(req.handle))
+        .and_then(|(store, peer)| store.long_term_key(&peer, req.ediv, &req.rand));
+    let ret = match key {
+        Some(key) => {
+            let params = raw::sdc_hci_cmd_le_long_term_key_request_reply_t {
+                conn_handle: req.handle,
+                long_term_key: key,
+            };
+            let mut ret = unsafe { core::mem::zeroed::<raw::sdc_hci_cmd_le_long_term_key_request_reply_return_t>() };
+            hci::status(unsafe { raw::sdc_hci_cmd_le_long_term_key_request_reply(&params, &mut ret) })
+        }
+        None => {
+            debug!("[security] no key for connection {}", req.handle);
+            let params = raw::sdc_hci_cmd_le_long_term_key_request_negative_reply_t {
+                conn_handle: req.handle,
+            };
+            let mut ret =
+                unsafe { core::mem::zeroed::<raw::sdc_hci_cmd_le_long_term_key_request_negative_reply_return_t>() };
+            hci::status(unsafe { raw::sdc_hci_cmd_le_long_term_key_request_negative_reply(&params, &mut ret) })
+        }
+    };
+    if let Err(e) = ret {
+        warn!("[security] failed to answer key request: {:?}", e);
+    }
+}
+
+#[cfg(any(feature = "central", feature = "multirole"))]
+pub(crate) fn enable_encryption(handle: u16, key: &LongTermKey) -> Result<(), Error> {
+    let params = raw::sdc_hci_cmd_le_enable_encryption_t {
+        conn_handle: handle,
+        random_number: key.rand,
+        encrypted_diversifier: key.ediv.to_le_bytes(),
+        long_term_key: key.key,
+    };
+    hci::status(unsafe { raw::sdc_hci_cmd_le_enable_encryption(&params) })
+}
@@ -0,0 +1,354 @@
+//! Radio TX power: the default level, per-role and per-handle overrides, and
+//! per-channel envelopes limiting the power on each channel.
+//!
+//! The default level and the envelopes are part of the
+//! [`sdc::Config`](crate::sdc::Config) and applied by
+//! [`sdc_init`](crate::sdc::sdc_init). Overrides can be changed at any time.
+use super::hci;
+use super::raw;
+use super::Error;
+
+/// Number of Bluetooth LE channels covered by an envelope.
+pub const NUM_CHANNELS: usize = raw::MPSL_TOTAL_NUM_OF_BLE_CHANNELS as usize;
+
+/// TX power levels supported by the RADIO peripheral, in dBm. Only the levels
+/// of the chip feature are available:
+///
+/// | Chip                                       | Levels                                          |
+/// |--------------------------------------------|-------------------------------------------------|
+/// | nRF52805, nRF52810, nRF52811, nRF52832     | -40, -20 to 0 in steps of 4, +3, +4             |
+/// | nRF52820, nRF52833, nRF52840               | -40, -20 to 0 in steps of 4, +2 to +8           |
+/// | nRF5340 network core                       | -40, -20 to -8 in steps of 4, -7 to 0, +3       |
+/// | nRF54L15                                   | -40, -20 to -8 in steps of 4, -7 to 0, +2 to +8 |
+///
+/// On the nRF5340, MPSL reaches +3 dBm by raising the radio supply voltage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[repr(i8)]
+pub enum TxPower {
+    Minus40dBm = -40,
+    Minus20dBm = -20,
+    Minus16dBm = -16,
+    Minus12dBm = -12,
+    Minus8dBm = -8,
+    #[cfg(any(feature = "nrf5340-net", feature = "nrf54l15"))]
+    Minus7dBm = -7,
+    #[cfg(any(feature = "nrf5340-net", feature = "nrf54l15"))]
+    Minus6dBm = -6,
+    #[cfg(any(feature = "nrf5340-net", feature = "nrf54l15"))]
+    Minus5dBm = -5,
+    Minus4dBm = -4,
+    #[cfg(any(feature = "nrf5340-net", feature = "nrf54l15"))]
+    Minus3dBm = -3,
+    #[cfg(any(feature = "nrf5340-net", feature = "nrf54l15"))]
+    Minus2dBm = -2,
+    #[cfg(any(feature = "nrf5340-net", feature = "nrf54l15"))]
+    Minus1dBm = -1,
+    ZerodBm = 0,
+    #[cfg(any(
+        feature = "nrf52820",
+        feature = "nrf52833",
+        feature = "nrf52840",
+        feature = "nrf54l15"
+    ))]
+    Plus2dBm = 2,
+    Plus3dBm = 3,
+    #[cfg(not(feature = "nrf5340-net"))]
+    Plus4dBm = 4,
+    #[cfg(any(
+        feature = "nrf52820",
+        feature = "nrf52833",
+        feature = "nrf52840",
+        feature = "nrf54l15"
+    ))]
+    Plus5dBm = 5,
+    #[cfg(any(
+        feature = "nrf52820",
+        feature = "nrf52833",
+        feature = "nrf52840",
+        feature = "nrf54l15"
+    ))]
+    Plus6dBm = 6,
+    #[cfg(any(
+        feature = "nrf52820",
+        feature = "nrf52833",
+        feature = "nrf52840",
+        feature = "nrf54l15"
+    ))]
+    Plus7dBm = 7,
+    #[cfg(any(
+        feature = "nrf52820",
+        feature = "nrf52833",
+        feature = "nrf52840",
+        feature = "nrf54l15"
+    ))]
+    Plus8dBm = 8,
+}
+
+impl TxPower {
+    /// All levels of the chip, from lowest to highest.
+    pub const ALL: &'static [Self] = &[
+        Self::Minus40dBm,
+        Self::Minus20dBm,
+        Self::Minus16dBm,
+        Self::Minus12dBm,
+        Self::Minus8dBm,
+        #[cfg(any(feature = "nrf5340-net", feature = "nrf54l15"))]
+        Self::Minus7dBm,
+        #[cfg(any(feature = "nrf5340-net", feature = "nrf54l15"))]
+        Self::Minus6dBm,
+        #[cfg(any(feature = "nrf5340-net", feature = "nrf54l15"))]
+        Self::Minus5dBm,
+        Self::Minus4dBm,
+        #[cfg(any(feature = "nrf5340-net", feature = "nrf54l15"))]
+        Self::Minus3dBm,
+        #[cfg(any(feature = "nrf5340-net", feature = "nrf54l15"))]
+        Self::Minus2dBm,
+        #[cfg(any(feature = "nrf5340-net", feature = "nrf54l15"))]
+        Self::Minus1dBm,
+        Self::ZerodBm,
+        #[cfg(any(
+            feature = "nrf52820",
+            feature = "nrf52833",
+            feature = "nrf52840",
+            feature = "nrf54l15"
+        ))]
+        Self::Plus2dBm,
+        Self::Plus3dBm,
+        #[cfg(not(feature = "nrf5340-net"))]
+        Self::Plus4dBm,
+        #[cfg(any(
+            feature = "nrf52820",
+            feature = "nrf52833",
+            feature = "nrf52840",
+            feature = "nrf54l15"
+        ))]
+        Self::Plus5dBm,
+        #[cfg(any(
+            feature = "nrf52820",
+            feature = "nrf52833",
+            feature = "nrf52840",
+            feature = "nrf54l15"
+        ))]
+        Self::Plus6dBm,
+        #[cfg(any(
+            feature = "nrf52820",
+            feature = "nrf52833",
+            feature = "nrf52840",
+            feature = "nrf54l15"
+        ))]
+        Self::Plus7dBm,
+        #[cfg(any(
+            feature = "nrf52820",
+            feature = "nrf52833",
+            feature = "nrf52840",
+            feature = "nrf54l15"
+        ))]
+        Self::Plus8dBm,
+    ];
+
+    pub fn dbm(self) -> i8 {
+        self as i8
+    }
+
+    /// The level of exactly `dbm`, if supported.
+    pub fn from_dbm(dbm: i8) -> Option<Self> {
+        Self::ALL.iter().copied().find(|p| p.dbm() == dbm)
+    }
+
+    /// The highest level not above `dbm`, or the lowest level if `dbm` is
+    /// below all of them.
+    pub fn at_most(dbm: i8) -> Self {
+        Self::ALL
+            .iter()
+            .copied()
+            .rev()
+            .find(|p| p.dbm() <= dbm)
+            .unwrap_or(Self::Minus40dBm)
+    }
+}
+
+impl TryFrom<i8> for TxPower {
+    type Error = Error;
+
+    fn try_from(dbm: i8) -> Result<Self, Error> {
+        Self::from_dbm(dbm).ok_or(Error::InvalidArg)
+    }
+}
+
+/// What a TX power override applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum TxPowerTarget {
+    /// The advertising set with the given handle, see
+    /// [`AdvSet::handle`](crate::adv::AdvSet::handle).
+    Advertising(u8),
+    /// Scanning and connection initiation.
+    ScanInit,
+    /// The connection with the given handle, see
+    /// [`Connection::handle`](crate::conn::Connection::handle).
+    Connection(u16),
+}
+
+impl TxPowerTarget {
+    fn raw(self) -> (u8, u16) {
+        match self {
+            Self::Advertising(handle) => (
+                raw::sdc_hci_vs_tx_power_handle_type_SDC_HCI_VS_TX_POWER_HANDLE_TYPE_ADV as u8,
+                handle as u16,
+            ),
+            Self::ScanInit => (
+                raw::sdc_hci_vs_tx_power_handle_type_SDC_HCI_VS_TX_POWER_HANDLE_TYPE_SCAN_INIT as u8,
+                0,
+            ),
+            Self::Connection(handle) => (
+                raw::sdc_hci_vs_tx_power_handle_type_SDC_HCI_VS_TX_POWER_HANDLE_TYPE_CONN as u8,
+                handle,
+            ),
+        }
+    }
+}
+
+/// Tells the controller to go back to the default level.
+const TX_POWER_DEFAULT: i8 = 0x7F;
+
+/// Override the TX power of `target`, returning the level selected by the controller in dBm.
+pub fn set_tx_power(target: TxPowerTarget, power: TxPower) -> Result<i8, Error> {
+    write_tx_power(target, power.dbm())
+}
+
+/// Remove the override of `target`, going back to the default level.
+pub fn reset_tx_power(target: TxPowerTarget) -> Result<i8, Error> {
+    write_tx_power(target, TX_POWER_DEFAULT)
+}
+
+fn write_tx_power(target: TxPowerTarget, tx_power_level: i8) -> Result<i8, Error> {
+    let (handle_type, handle) = target.raw();
+    let params = raw::sdc_hci_cmd_vs_zephyr_write_tx_power_t {
+        handle_type,
+        handle,
+        tx_power_level,
+    };
+    let mut ret = unsafe { core::mem::zeroed::<raw::sdc_hci_cmd_vs_zephyr_write_tx_power_return_t>() };
+    hci::status(unsafe { raw::sdc_hci_cmd_vs_zephyr_write_tx_power(&params, &mut ret) })?;
+    Ok(ret.selected_tx_power)
+}
+
+/// The TX power in use for `target`, in dBm.
+pub fn tx_power(target: TxPowerTarget) -> Result<i8, Error> {
+    let (handle_type, handle) = target.raw();
+    let params = raw::sdc_hci_cmd_vs_zephyr_read_tx_power_t { handle_type, handle };
+    let mut ret = unsafe { core::mem::zeroed::<raw::sdc_hci_cmd_vs_zephyr_read_tx_power_return_t>() };
+    hci::status(unsafe { raw::sdc_hci_cmd_vs_zephyr_read_tx_power(&params, &mut ret) })?;
+    Ok(ret.selected_tx_power)
+}
+
+/// The minimum and maximum TX power supported by the controller, in dBm.
+pub fn supported_range() -> Result<(i8, i8), Error> {
+    let mut ret = unsafe { core::mem::zeroed::<raw::sdc_hci_cmd_le_read_transmit_power_return_t>() };
+    hci::status(unsafe { raw::sdc_hci_cmd_le_read_transmit_power(&mut ret) })?;
+    Ok((ret.min_tx_power, ret.max_tx_power))
+}
+
+/// The PHYs an envelope can apply to, Coded split by coding scheme.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum EnvelopePhy {
+    Le1M,
+    Le2M,
+    /// Coded PHY with S=8 coding.
+    LeCoded125k,
+    /// Coded PHY with S=2 coding.
+    LeCoded500k,
+}
+
+impl EnvelopePhy {
+    const fn raw(self) -> raw::mpsl_phy_t {
+        match self {
+            Self::Le1M => raw::mpsl_phy_t_MPSL_PHY_BLE_1M,
+            Self::Le2M => raw::mpsl_phy_t_MPSL_PHY_BLE_2M,
+            Self::LeCoded125k => raw::mpsl_phy_t_MPSL_PHY_BLE_LR125Kbit,
+            Self::LeCoded500k => raw::mpsl_phy_t_MPSL_PHY_BLE_LR500Kbit,
+        }
+    }
+}
+
+/// Maximum TX power on each channel of one PHY, for example to reduce the power
+/// on the band edge channels.
+///
+/// Channels are numbered by channel index: 0 to 36 are data channels and 37 to
+/// 39 the primary advertising channels.
+pub struct TxPowerEnvelope {
+    phy: EnvelopePhy,
+    raw: raw::mpsl_tx_power_envelope_t,
+}
+
+impl TxPowerEnvelope {
+    /// An envelope limiting all channels to `max`.
+    pub const fn new(phy: EnvelopePhy, max: TxPower) -> Self {
+        Self::from_channels(phy, [max; NUM_CHANNELS])
+    }
+
+    /// An envelope with the given limit on each channel.
+    pub const fn from_channels(phy: EnvelopePhy, max: [TxPower; NUM_CHANNELS]) -> Self {
+        let mut tx_power_ble = [0; NUM_CHANNELS];
+        let mut i = 0;
+        while i < NUM_CHANNELS {
+            tx_power_ble[i] = max[i] as i8;
+            i += 1;
+        }
+        Self {
+            phy,
+            raw: raw::mpsl_tx_power_envelope_t {
+                phy: phy.raw(),
+                envelope: raw::mpsl_tx_power_envelope_t__bindgen_ty_1 { tx_power_ble },
+            },
+        }
+    }
+
+    /// Limit `channels` to `max`.
+    pub fn set(&mut self, channels: core::ops::Range<usize>, max: TxPower) -> Result<(), Error> {
+        if channels.start > channels.end || channels.end > NUM_CHANNELS {
+            return Err(Error::InvalidArg);
+        }
+        // Only the Bluetooth LE variant is ever written.
+        let limits = unsafe { &mut self.raw.envelope.tx_power_ble };
+        limits[channels].fill(max.dbm());
+        Ok(())
+    }
+
+    /// The limit on `channel` in dBm.
+    pub fn max(&self, channel: usize) -> Option<i8> {
+        // Only the Bluetooth LE variant is ever written.
+        unsafe { self.raw.envelope.tx_power_ble }.get(channel).copied()
+    }
+
+    pub fn phy(&self) -> EnvelopePhy {
+        self.phy
+    }
+}
+
+/// Set the TX power used when no override applies. Must be called before the
+/// controller is enabled.
+pub(crate) fn set_default(power: TxPower) -> Result<(), Error> {
+    let ret = unsafe { raw::sdc_default_tx_power_set(power.dbm()) };
+    if ret != 0 {
+        return Err(ret.into());
+    }
+    Ok(())
+}
+
+/// Install `envelopes`, each replacing the previous envelope of its PHY.
+///
+/// MPSL keeps pointers to the envelopes, hence the `'static` lifetime. They are
+/// only installed before the controller is enabled, as replacing an envelope in
+/// use is undefined behavior.
+pub(crate) fn set_envelopes(envelopes: &'static [TxPowerEnvelope]) -> Result<(), Error> {
+    for envelope in envelopes {
+        let ret = unsafe { raw::mpsl_tx_power_channel_map_set(&envelope.raw) };
+        if ret != 0 {
+            return Err(ret.into());
+        }
+    }
+    Ok(())
+}